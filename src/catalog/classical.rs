@@ -0,0 +1,270 @@
+use crate::linear_code::PyLinearCode;
+use ldpc::classical::LinearCode;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use sparse_bin_mat::SparseBinMat;
+
+// The largest supported order of Hamming codes.
+// This limits the length of the codes to about a million bits.
+const MAX_ORDER: usize = 20;
+
+// The largest supported number of variables of Reed-Muller codes.
+// Either the parity check matrix or the generator matrix has at least
+// half as many rows as columns and the other one is obtained
+// by Gaussian elimination, which already takes seconds for 4096 bits.
+const MAX_REED_MULLER_VARIABLES: usize = 12;
+
+// A primitive polynomial of each degree from 2 to 16
+// where the bit at position i is the coefficient of x^i.
+const PRIMITIVE_POLYNOMIALS: [usize; 15] = [
+    0x7, 0xB, 0x13, 0x25, 0x43, 0x83, 0x11D, 0x211, 0x409, 0x805, 0x1053, 0x201B, 0x4443, 0x8003,
+    0x1100B,
+];
+
+pub(crate) fn hamming_code(order: usize) -> PyResult<PyLinearCode> {
+    if !(2..=MAX_ORDER).contains(&order) {
+        return Err(PyValueError::new_err(format!(
+            "order of Hamming code must be between 2 and {}, not {}",
+            MAX_ORDER, order
+        )));
+    }
+    Ok(LinearCode::from_parity_check_matrix(hamming_parity_check_matrix(order)).into())
+}
+
+// The column j is the binary representation of j + 1
+// with the most significant bit in the first row.
+pub(super) fn hamming_parity_check_matrix(order: usize) -> SparseBinMat {
+    let length = (1 << order) - 1;
+    let checks = (0..order)
+        .map(|check| {
+            let mask = 1 << (order - 1 - check);
+            (0..length).filter(|bit| (bit + 1) & mask != 0).collect()
+        })
+        .collect();
+    SparseBinMat::new(length, checks)
+}
+
+pub(crate) fn bch_code(order: usize, designed_distance: usize) -> PyResult<PyLinearCode> {
+    let field = GaloisField::with_degree(order)?;
+    let length = field.num_non_zero_elements();
+    if designed_distance == 0 || designed_distance > length {
+        return Err(PyValueError::new_err(format!(
+            "designed distance must be between 1 and {}, not {}",
+            length, designed_distance
+        )));
+    }
+    let mut generator_polynomial = vec![1];
+    let mut used_exponents = vec![false; length];
+    for exponent in 1..designed_distance {
+        if !used_exponents[exponent] {
+            let coset = field.cyclotomic_coset(exponent);
+            for element in coset.iter() {
+                used_exponents[*element] = true;
+            }
+            generator_polynomial =
+                multiply_polynomials(&generator_polynomial, &field.minimal_polynomial(&coset));
+        }
+    }
+    let mut cyclic_polynomial = vec![0; length + 1];
+    cyclic_polynomial[0] = 1;
+    cyclic_polynomial[length] = 1;
+    let check_polynomial = divide_polynomials(&cyclic_polynomial, &generator_polynomial);
+    let dimension = check_polynomial.len() - 1;
+    // The checks are the cyclic shifts of the reciprocal of the check polynomial.
+    let checks = (0..length - dimension)
+        .map(|shift| {
+            (0..=dimension)
+                .filter(|degree| check_polynomial[dimension - degree] == 1)
+                .map(|degree| degree + shift)
+                .collect()
+        })
+        .collect();
+    let matrix = SparseBinMat::new(length, checks);
+    Ok(LinearCode::from_parity_check_matrix(matrix).into())
+}
+
+pub(crate) fn reed_muller_code(degree: usize, num_variables: usize) -> PyResult<PyLinearCode> {
+    if num_variables > MAX_REED_MULLER_VARIABLES {
+        return Err(PyValueError::new_err(format!(
+            "number of variables must be at most {}, not {}",
+            MAX_REED_MULLER_VARIABLES, num_variables
+        )));
+    }
+    if degree > num_variables {
+        return Err(PyValueError::new_err(format!(
+            "degree {} is larger than the number of variables {}",
+            degree, num_variables
+        )));
+    }
+    // The dual of RM(r, m) is RM(m - r - 1, m).
+    let matrix = match num_variables.checked_sub(degree + 1) {
+        Some(dual_degree) => reed_muller_generator_matrix(dual_degree, num_variables),
+        None => SparseBinMat::zeros(0, 1 << num_variables),
+    };
+    Ok(LinearCode::from_parity_check_matrix(matrix).into())
+}
+
+// Each row is the evaluation of a monomial of at most the given degree
+// on all the points of F_2^m where the bit i of a point is the value of x_i.
+pub(super) fn reed_muller_generator_matrix(degree: usize, num_variables: usize) -> SparseBinMat {
+    let length: usize = 1 << num_variables;
+    let mut monomials: Vec<usize> = (0..length)
+        .filter(|monomial| monomial.count_ones() as usize <= degree)
+        .collect();
+    monomials.sort_by_key(|monomial| monomial.count_ones());
+    let rows = monomials
+        .into_iter()
+        .map(|monomial| {
+            (0..length)
+                .filter(|point| point & monomial == monomial)
+                .collect()
+        })
+        .collect();
+    SparseBinMat::new(length, rows)
+}
+
+pub(crate) fn golay_code(extended: bool) -> PyLinearCode {
+    // The generator matrix of the extended code is [I | B].
+    const B: [u16; 12] = [
+        0b110111000101,
+        0b101110001011,
+        0b011100010111,
+        0b111000101101,
+        0b110001011011,
+        0b100010110111,
+        0b000101101111,
+        0b001011011101,
+        0b010110111001,
+        0b101101110001,
+        0b011011100011,
+        0b111111111110,
+    ];
+    let length = if extended { 24 } else { 23 };
+    let rows = B
+        .iter()
+        .enumerate()
+        .map(|(row, bits)| {
+            std::iter::once(row)
+                .chain(
+                    (0..12)
+                        .filter(|bit| bits & (1 << (11 - bit)) != 0)
+                        .map(|bit| bit + 12),
+                )
+                .filter(|column| *column < length)
+                .collect()
+        })
+        .collect();
+    let matrix = SparseBinMat::new(length, rows);
+    if extended {
+        // The extended Golay code is self-dual.
+        LinearCode::from_parity_check_matrix(matrix).into()
+    } else {
+        LinearCode::from_generator_matrix(matrix).into()
+    }
+}
+
+// The field with 2^m elements represented as polynomials in a primitive element α.
+// Elements are stored as integers where the bit i is the coefficient of α^i.
+struct GaloisField {
+    powers: Vec<usize>,
+    logarithms: Vec<usize>,
+}
+
+impl GaloisField {
+    fn with_degree(degree: usize) -> PyResult<Self> {
+        let polynomial = degree
+            .checked_sub(2)
+            .and_then(|index| PRIMITIVE_POLYNOMIALS.get(index))
+            .ok_or_else(|| {
+                PyValueError::new_err(format!(
+                    "order must be between 2 and {}, not {}",
+                    PRIMITIVE_POLYNOMIALS.len() + 1,
+                    degree
+                ))
+            })?;
+        let num_non_zero_elements = (1 << degree) - 1;
+        let mut powers = Vec::with_capacity(num_non_zero_elements);
+        let mut logarithms = vec![0; num_non_zero_elements + 1];
+        let mut element = 1;
+        for exponent in 0..num_non_zero_elements {
+            powers.push(element);
+            logarithms[element] = exponent;
+            element <<= 1;
+            if element & (1 << degree) != 0 {
+                element ^= polynomial;
+            }
+        }
+        Ok(Self { powers, logarithms })
+    }
+
+    fn num_non_zero_elements(&self) -> usize {
+        self.powers.len()
+    }
+
+    // Returns the exponents {e, 2e, 4e, ...} modulo 2^m - 1.
+    fn cyclotomic_coset(&self, exponent: usize) -> Vec<usize> {
+        let mut coset = vec![exponent];
+        let mut next = (2 * exponent) % self.num_non_zero_elements();
+        while next != exponent {
+            coset.push(next);
+            next = (2 * next) % self.num_non_zero_elements();
+        }
+        coset
+    }
+
+    fn multiply(&self, left: usize, right: usize) -> usize {
+        if left == 0 || right == 0 {
+            return 0;
+        }
+        let exponent = self.logarithms[left] + self.logarithms[right];
+        self.powers[exponent % self.num_non_zero_elements()]
+    }
+
+    // Returns the binary coefficients of the product of (x + α^e)
+    // for all exponents e in the coset.
+    fn minimal_polynomial(&self, coset: &[usize]) -> Vec<u8> {
+        let mut coefficients = vec![1];
+        for exponent in coset {
+            let root = self.powers[*exponent];
+            let mut next = vec![0; coefficients.len() + 1];
+            for (degree, coefficient) in coefficients.iter().enumerate() {
+                next[degree + 1] ^= coefficient;
+                next[degree] ^= self.multiply(root, *coefficient);
+            }
+            coefficients = next;
+        }
+        // The coefficients of a minimal polynomial are either 0 or 1.
+        coefficients
+            .into_iter()
+            .map(|coefficient| coefficient as u8)
+            .collect()
+    }
+}
+
+// Polynomials over GF(2) are stored as their coefficients from degree 0.
+
+fn multiply_polynomials(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut product = vec![0; left.len() + right.len() - 1];
+    for (i, a) in left.iter().enumerate() {
+        for (j, b) in right.iter().enumerate() {
+            product[i + j] ^= a & b;
+        }
+    }
+    product
+}
+
+// Assumes that the divisor is non-zero and divides the dividend.
+fn divide_polynomials(dividend: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let divisor_degree = divisor.len() - 1;
+    let mut remainder = dividend.to_vec();
+    let mut quotient = vec![0; dividend.len() - divisor_degree];
+    for degree in (0..quotient.len()).rev() {
+        if remainder[degree + divisor_degree] == 1 {
+            quotient[degree] = 1;
+            for (offset, coefficient) in divisor.iter().enumerate() {
+                remainder[degree + offset] ^= coefficient;
+            }
+        }
+    }
+    quotient
+}
@@ -0,0 +1,12 @@
+mod classical;
+pub(crate) use classical::{bch_code, golay_code, hamming_code, reed_muller_code};
+
+mod planar;
+pub(crate) use planar::{planar_surface_code, PlanarLattice, Site};
+
+mod quantum;
+pub(crate) use quantum::{
+    bacon_shor_code, five_qubit_code, four_two_two_code, quantum_reed_muller_code,
+    reed_muller_css_code, reed_muller_triorthogonal_code, subsystem_bacon_shor_code,
+    triorthogonal_code,
+};
@@ -0,0 +1,126 @@
+use super::quantum::css_code_from_stabilizers;
+use crate::css_code::PyCssCode;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use sparse_bin_mat::SparseBinMat;
+
+/// The layout of a planar surface code on a square grid.
+///
+/// The grid has 2d - 1 rows and columns for a code of distance d.
+/// The sites with an even sum of row and column are qubits,
+/// the sites with an even row and an odd column are X stabilizers
+/// and the sites with an odd row and an even column are Z stabilizers.
+/// Each stabilizer acts on the qubits of the neighboring sites
+/// and each type of site is indexed in row major order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct PlanarLattice {
+    distance: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Site {
+    Qubit(usize),
+    XStabilizer(usize),
+    ZStabilizer(usize),
+}
+
+impl PlanarLattice {
+    pub(crate) fn new(distance: usize) -> PyResult<Self> {
+        if distance < 2 {
+            return Err(PyValueError::new_err(format!(
+                "planar surface code needs a distance of at least 2, not {}",
+                distance
+            )));
+        }
+        Ok(Self { distance })
+    }
+
+    pub(crate) fn distance(&self) -> usize {
+        self.distance
+    }
+
+    /// The number of rows and of columns of the grid.
+    pub(crate) fn size(&self) -> usize {
+        2 * self.distance - 1
+    }
+
+    pub(crate) fn num_qubits(&self) -> usize {
+        self.distance * self.distance + (self.distance - 1) * (self.distance - 1)
+    }
+
+    pub(crate) fn site(&self, row: usize, column: usize) -> Site {
+        match (row % 2, column % 2) {
+            (0, 0) => Site::Qubit((row / 2) * self.size() + column / 2),
+            (1, 1) => Site::Qubit((row / 2) * self.size() + self.distance + column / 2),
+            (0, _) => Site::XStabilizer((row / 2) * (self.distance - 1) + column / 2),
+            _ => Site::ZStabilizer((row / 2) * self.distance + column / 2),
+        }
+    }
+
+    /// Returns the sites next to the given site inside the grid.
+    pub(crate) fn neighbors(&self, row: usize, column: usize) -> Vec<(usize, usize)> {
+        let mut neighbors = Vec::with_capacity(4);
+        if row > 0 {
+            neighbors.push((row - 1, column));
+        }
+        if column > 0 {
+            neighbors.push((row, column - 1));
+        }
+        if column + 1 < self.size() {
+            neighbors.push((row, column + 1));
+        }
+        if row + 1 < self.size() {
+            neighbors.push((row + 1, column));
+        }
+        neighbors
+    }
+
+    /// The qubits of the X logical along the first column.
+    pub(crate) fn x_logical(&self) -> Vec<usize> {
+        (0..self.distance)
+            .map(|row| self.qubit_index(2 * row, 0))
+            .collect()
+    }
+
+    /// The qubits of the Z logical along the first row.
+    pub(crate) fn z_logical(&self) -> Vec<usize> {
+        (0..self.distance)
+            .map(|column| self.qubit_index(0, 2 * column))
+            .collect()
+    }
+
+    pub(crate) fn code(&self) -> PyCssCode {
+        let mut x_stabilizers = vec![Vec::new(); self.distance * (self.distance - 1)];
+        let mut z_stabilizers = vec![Vec::new(); self.distance * (self.distance - 1)];
+        for row in 0..self.size() {
+            for column in 0..self.size() {
+                let stabilizer = match self.site(row, column) {
+                    Site::Qubit(_) => continue,
+                    Site::XStabilizer(index) => &mut x_stabilizers[index],
+                    Site::ZStabilizer(index) => &mut z_stabilizers[index],
+                };
+                *stabilizer = self
+                    .neighbors(row, column)
+                    .into_iter()
+                    .map(|(row, column)| self.qubit_index(row, column))
+                    .collect();
+                stabilizer.sort_unstable();
+            }
+        }
+        css_code_from_stabilizers(
+            SparseBinMat::new(self.num_qubits(), x_stabilizers),
+            SparseBinMat::new(self.num_qubits(), z_stabilizers),
+        )
+    }
+
+    fn qubit_index(&self, row: usize, column: usize) -> usize {
+        match self.site(row, column) {
+            Site::Qubit(index) => index,
+            _ => unreachable!("site ({}, {}) is not a qubit", row, column),
+        }
+    }
+}
+
+pub(crate) fn planar_surface_code(distance: usize) -> PyResult<PyCssCode> {
+    PlanarLattice::new(distance).map(|lattice| lattice.code())
+}
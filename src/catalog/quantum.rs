@@ -0,0 +1,201 @@
+use super::classical::{hamming_parity_check_matrix, reed_muller_generator_matrix};
+use crate::css_code::PyCssCode;
+use crate::stabilizer_code::PyStabilizerCode;
+use crate::subsystem_code::PySubsystemCode;
+use ldpc::classical::LinearCode;
+use ldpc::quantum::CssCode;
+use pauli::{PauliOperator, X, Z};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use sparse_bin_mat::SparseBinMat;
+
+// The largest supported order of quantum Reed-Muller codes.
+const MAX_QUANTUM_REED_MULLER_ORDER: usize = 10;
+
+pub(crate) fn five_qubit_code() -> PyStabilizerCode {
+    // The stabilizers are the cyclic shifts of XZZXI.
+    let pattern = [Some(X), Some(Z), Some(Z), Some(X), None];
+    let stabilizers: Vec<PauliOperator> = (0..4)
+        .map(|shift| {
+            let (positions, paulis) = (0..5)
+                .filter_map(|position| {
+                    pattern[(position + 5 - shift) % 5].map(|pauli| (position, pauli))
+                })
+                .unzip();
+            PauliOperator::new(5, positions, paulis)
+        })
+        .collect();
+    PyStabilizerCode::try_from_operators(&stabilizers).unwrap()
+}
+
+pub(crate) fn four_two_two_code() -> PyCssCode {
+    let stabilizers = SparseBinMat::new(4, vec![vec![0, 1, 2, 3]]);
+    css_code_from_stabilizers(stabilizers.clone(), stabilizers)
+}
+
+pub(crate) fn bacon_shor_code(rows: usize, columns: usize) -> PyResult<PyCssCode> {
+    if rows < 2 || columns < 2 {
+        return Err(PyValueError::new_err(format!(
+            "Bacon-Shor code needs at least 2 rows and 2 columns, not {} by {}",
+            rows, columns
+        )));
+    }
+    // Qubit (r, c) has index r * columns + c.
+    let x_stabilizers = (0..rows - 1)
+        .map(|row| (row * columns..(row + 2) * columns).collect())
+        .collect();
+    let z_stabilizers = (0..columns - 1)
+        .map(|column| {
+            (0..rows)
+                .flat_map(|row| vec![row * columns + column, row * columns + column + 1])
+                .collect()
+        })
+        .collect();
+    Ok(css_code_from_stabilizers(
+        SparseBinMat::new(rows * columns, x_stabilizers),
+        SparseBinMat::new(rows * columns, z_stabilizers),
+    ))
+}
+
+pub(crate) fn subsystem_bacon_shor_code(rows: usize, columns: usize) -> PyResult<PySubsystemCode> {
+    if rows < 2 || columns < 2 {
+        return Err(PyValueError::new_err(format!(
+            "Bacon-Shor code needs at least 2 rows and 2 columns, not {} by {}",
+            rows, columns
+        )));
+    }
+    let length = rows * columns;
+    // The X gauge generators act on vertical pairs of qubits
+    // and the Z gauge generators act on horizontal pairs of qubits.
+    let x_gauges = (0..rows - 1).flat_map(|row| {
+        (0..columns).map(move |column| {
+            let qubit = row * columns + column;
+            PauliOperator::new(length, vec![qubit, qubit + columns], vec![X, X])
+        })
+    });
+    let z_gauges = (0..rows).flat_map(|row| {
+        (0..columns - 1).map(move |column| {
+            let qubit = row * columns + column;
+            PauliOperator::new(length, vec![qubit, qubit + 1], vec![Z, Z])
+        })
+    });
+    let gauge_generators: Vec<PauliOperator> = x_gauges.chain(z_gauges).collect();
+    PySubsystemCode::try_from_operators(&gauge_generators)
+}
+
+pub(crate) fn quantum_reed_muller_code(order: usize) -> PyResult<PyCssCode> {
+    if !(3..=MAX_QUANTUM_REED_MULLER_ORDER).contains(&order) {
+        return Err(PyValueError::new_err(format!(
+            "order of quantum Reed-Muller code must be between 3 and {}, not {}",
+            MAX_QUANTUM_REED_MULLER_ORDER, order
+        )));
+    }
+    let x_stabilizers = hamming_parity_check_matrix(order);
+    let length = x_stabilizers.number_of_columns();
+    let all_ones = SparseBinMat::new(length, vec![(0..length).collect()]);
+    let z_stabilizers = x_stabilizers.vertical_concat_with(&all_ones).nullspace();
+    Ok(css_code_from_stabilizers(x_stabilizers, z_stabilizers))
+}
+
+pub(crate) fn reed_muller_css_code(degree: usize, num_variables: usize) -> PyResult<PyCssCode> {
+    if num_variables > MAX_QUANTUM_REED_MULLER_ORDER {
+        return Err(PyValueError::new_err(format!(
+            "number of variables must be at most {}, not {}",
+            MAX_QUANTUM_REED_MULLER_ORDER, num_variables
+        )));
+    }
+    // The stabilizers RM(m - r - 1, m) are the dual of RM(r, m)
+    // and are contained in RM(r, m) if m - r - 1 <= r.
+    if degree >= num_variables || 2 * degree + 1 < num_variables {
+        return Err(PyValueError::new_err(format!(
+            "degree must be between {} and {} for {} variables, not {}",
+            num_variables / 2,
+            num_variables.saturating_sub(1),
+            num_variables,
+            degree
+        )));
+    }
+    let stabilizers = reed_muller_generator_matrix(num_variables - degree - 1, num_variables);
+    Ok(css_code_from_stabilizers(stabilizers.clone(), stabilizers))
+}
+
+pub(crate) fn reed_muller_triorthogonal_code(
+    degree: usize,
+    num_variables: usize,
+) -> PyResult<PyCssCode> {
+    if num_variables > MAX_QUANTUM_REED_MULLER_ORDER {
+        return Err(PyValueError::new_err(format!(
+            "number of variables must be at most {}, not {}",
+            MAX_QUANTUM_REED_MULLER_ORDER, num_variables
+        )));
+    }
+    // The product of three codewords of RM(r, m) is in RM(3r, m)
+    // which only has even weight codewords if 3r < m.
+    if degree == 0 || 3 * degree >= num_variables {
+        return Err(PyValueError::new_err(format!(
+            "degree must be positive and less than a third of the {} variables, not {}",
+            num_variables, degree
+        )));
+    }
+    // Puncturing the first position turns the constant monomial
+    // into the only odd weight row.
+    let matrix = reed_muller_generator_matrix(degree, num_variables);
+    let rows = matrix
+        .rows()
+        .map(|row| {
+            row.non_trivial_positions()
+                .filter(|position| *position > 0)
+                .map(|position| position - 1)
+                .collect()
+        })
+        .collect();
+    triorthogonal_code(&SparseBinMat::new(matrix.number_of_columns() - 1, rows))
+}
+
+pub(crate) fn triorthogonal_code(matrix: &SparseBinMat) -> PyResult<PyCssCode> {
+    let rows: Vec<Vec<usize>> = matrix.rows().map(|row| row.as_slice().to_vec()).collect();
+    for (first, first_row) in rows.iter().enumerate() {
+        for (second, second_row) in rows.iter().enumerate().skip(first + 1) {
+            let overlap = intersection(first_row, second_row);
+            if overlap.len() % 2 == 1 {
+                return Err(PyValueError::new_err(format!(
+                    "rows {} and {} have an odd overlap",
+                    first, second
+                )));
+            }
+            for (third, third_row) in rows.iter().enumerate().skip(second + 1) {
+                if intersection(&overlap, third_row).len() % 2 == 1 {
+                    return Err(PyValueError::new_err(format!(
+                        "rows {}, {} and {} have an odd overlap",
+                        first, second, third
+                    )));
+                }
+            }
+        }
+    }
+    let even_rows = rows.into_iter().filter(|row| row.len() % 2 == 0).collect();
+    let x_stabilizers = SparseBinMat::new(matrix.number_of_columns(), even_rows);
+    Ok(css_code_from_stabilizers(x_stabilizers, matrix.nullspace()))
+}
+
+// This assumes that the X and Z stabilizers are orthogonal.
+pub(super) fn css_code_from_stabilizers(
+    x_stabilizers: SparseBinMat,
+    z_stabilizers: SparseBinMat,
+) -> PyCssCode {
+    CssCode::try_new(
+        &LinearCode::from_parity_check_matrix(x_stabilizers),
+        &LinearCode::from_parity_check_matrix(z_stabilizers),
+    )
+    .unwrap()
+    .into()
+}
+
+// Returns the sorted elements in both sorted slices.
+fn intersection(first: &[usize], second: &[usize]) -> Vec<usize> {
+    first
+        .iter()
+        .filter(|element| second.binary_search(element).is_ok())
+        .cloned()
+        .collect()
+}
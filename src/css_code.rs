@@ -1,13 +1,21 @@
+use crate::logicals::PairedLogicals;
+use crate::packed::syndromes_of;
 use crate::pauli::PyPauliOperator;
-use crate::sparse::{PyBinaryMatrix, PyBinaryVector};
+use crate::sparse::{binary_matrix_from, PyBinaryMatrix, PyBinarySubspace, PyBinaryVector};
+use crate::symplectic::to_symplectic;
 use crate::PyLinearCode;
+use ldpc::classical::LinearCode;
 use ldpc::quantum::CssCode;
-use pyo3::exceptions::PyValueError;
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use pyo3::PyObjectProtocol;
 use pyo3::PySequenceProtocol;
 use pyo3::ToPyObject;
+use sparse_bin_mat::SparseBinVec;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 pub(crate) fn hypergraph_product(
     first_code: &PyLinearCode,
@@ -91,6 +99,126 @@ impl PyCssCode {
         self.inner.z_logicals_binary().clone().into()
     }
 
+    /// The subspace spanned by the X stabilizers.
+    #[pyo3(text_signature = "(self)")]
+    pub fn x_stabilizer_space(&self) -> PyBinarySubspace {
+        PyBinarySubspace::from_generators(self.inner.x_stabs_binary())
+    }
+
+    /// The subspace spanned by the Z stabilizers.
+    #[pyo3(text_signature = "(self)")]
+    pub fn z_stabilizer_space(&self) -> PyBinarySubspace {
+        PyBinarySubspace::from_generators(self.inner.z_stabs_binary())
+    }
+
+    /// The subspace of X operators commuting with all Z stabilizers.
+    ///
+    /// This is the kernel of the Z stabilizer matrix.
+    /// It contains the X stabilizers and the X logical operators.
+    #[pyo3(text_signature = "(self)")]
+    pub fn x_normalizer_space(&self) -> PyBinarySubspace {
+        PyBinarySubspace::orthogonal_to(self.inner.z_stabs_binary())
+    }
+
+    /// The subspace of Z operators commuting with all X stabilizers.
+    ///
+    /// This is the kernel of the X stabilizer matrix.
+    /// It contains the Z stabilizers and the Z logical operators.
+    #[pyo3(text_signature = "(self)")]
+    pub fn z_normalizer_space(&self) -> PyBinarySubspace {
+        PyBinarySubspace::orthogonal_to(self.inner.x_stabs_binary())
+    }
+
+    /// The subspace spanned by the X logical generators.
+    ///
+    /// Together with the X stabilizer space,
+    /// it spans the X normalizer space.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import steane_code
+    ///     >>> code = steane_code()
+    ///     >>> stabilizers = code.x_stabilizer_space()
+    ///     >>> code.x_logical_space().sum(stabilizers) == code.x_normalizer_space()
+    ///     True
+    #[pyo3(text_signature = "(self)")]
+    pub fn x_logical_space(&self) -> PyBinarySubspace {
+        PyBinarySubspace::from_generators(self.inner.x_logicals_binary())
+    }
+
+    /// The subspace spanned by the Z logical generators.
+    ///
+    /// Together with the Z stabilizer space,
+    /// it spans the Z normalizer space.
+    #[pyo3(text_signature = "(self)")]
+    pub fn z_logical_space(&self) -> PyBinarySubspace {
+        PyBinarySubspace::from_generators(self.inner.z_logicals_binary())
+    }
+
+    /// Returns X and Z logical generators paired such that
+    /// the i-th X logical anticommutes only with the i-th Z logical.
+    ///
+    /// The pairing is computed with a symplectic Gram-Schmidt process.
+    /// Thus, the i-th logical qubit is defined by the i-th rows of both matrices.
+    ///
+    /// Parameters
+    /// ----------
+    /// minimize_weight: bool
+    ///     If True, the weight of each logical is greedily reduced
+    ///     by multiplying it with stabilizer generators.
+    ///     This doesn't change the pairing.
+    ///
+    /// Returns
+    /// -------
+    /// (BinaryMatrix, BinaryMatrix)
+    ///     The X and Z logical generators with `x_logicals * z_logicals^T = I`.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import shor_code
+    ///     >>> x_logicals, z_logicals = shor_code().paired_logicals(minimize_weight=True)
+    ///     >>> x_logicals.dot_with_matrix(z_logicals.transposed())
+    ///     [0]
+    #[args(minimize_weight = "false")]
+    #[pyo3(text_signature = "(self, minimize_weight=False)")]
+    pub fn paired_logicals(&self, minimize_weight: bool) -> (PyBinaryMatrix, PyBinaryMatrix) {
+        let (x_logicals, z_logicals) = self
+            .logical_pairs(minimize_weight)
+            .to_matrices(self.length());
+        (x_logicals.into(), z_logicals.into())
+    }
+
+    /// Returns the code defined by the reduced row echelon forms
+    /// of the stabilizer matrices.
+    ///
+    /// Codes with the same stabilizer group have equal canonical forms.
+    /// Thus, canonical forms can be used to deduplicate codes in sets or dicts.
+    #[pyo3(text_signature = "(self)")]
+    pub fn canonical_form(&self) -> Self {
+        let x_code = LinearCode::from_parity_check_matrix(self.x_stabilizer_space().basis().inner);
+        let z_code = LinearCode::from_parity_check_matrix(self.z_stabilizer_space().basis().inner);
+        CssCode::new(&x_code, &z_code).into()
+    }
+
+    /// Checks if the other code has the same stabilizer group as this code.
+    ///
+    /// The logical operators are the operators commuting with the stabilizers,
+    /// so equivalent codes also have the same logical operators.
+    /// Unlike `==`, this doesn't depend on the choice of generators.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import steane_code
+    ///     >>> code = steane_code()
+    ///     >>> code.is_equivalent_to(code.canonical_form())
+    ///     True
+    #[pyo3(text_signature = "(self, other)")]
+    pub fn is_equivalent_to(&self, other: &Self) -> bool {
+        self.length() == other.length()
+            && self.x_stabilizer_space() == other.x_stabilizer_space()
+            && self.z_stabilizer_space() == other.z_stabilizer_space()
+    }
+
     /// The number of qubits in the code.
     ///
     ///     >>> len(code) == code.length()
@@ -146,14 +274,137 @@ impl PyCssCode {
         Ok((syndrome.x.into(), syndrome.z.into()))
     }
 
+    /// Computes the syndromes of many errors at once.
+    ///
+    /// Parameters
+    /// ----------
+    /// x_errors: BinaryMatrix or numpy.ndarray
+    ///     A matrix where each row is the X part of an error.
+    /// z_errors: BinaryMatrix or numpy.ndarray
+    ///     A matrix where each row is the Z part of an error.
+    ///
+    /// Returns
+    /// -------
+    /// (BinaryMatrix, BinaryMatrix)
+    ///     The X and Z syndromes where each row corresponds to an error.
+    ///     As for `syndrome_of`, the X syndromes are measured by the
+    ///     X stabilizers and correspond to the Z errors.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the number of columns is not the length of the code
+    ///     or if the matrices have different numbers of rows.
+    #[pyo3(text_signature = "(self, x_errors, z_errors)")]
+    pub fn syndromes_of(
+        &self,
+        x_errors: &PyAny,
+        z_errors: &PyAny,
+    ) -> PyResult<(PyBinaryMatrix, PyBinaryMatrix)> {
+        let x_errors = binary_matrix_from(x_errors)?;
+        let z_errors = binary_matrix_from(z_errors)?;
+        if x_errors.number_of_rows() != z_errors.number_of_rows() {
+            return Err(PyValueError::new_err(format!(
+                "{} X errors but {} Z errors",
+                x_errors.number_of_rows(),
+                z_errors.number_of_rows()
+            )));
+        }
+        let x_syndromes = syndromes_of(&z_errors, self.inner.x_stabs_binary())?;
+        let z_syndromes = syndromes_of(&x_errors, self.inner.z_stabs_binary())?;
+        Ok((x_syndromes.into(), z_syndromes.into()))
+    }
+
+    /// Checks if the operator commutes with all stabilizers.
+    ///
+    /// This includes the stabilizers themselves.
     #[pyo3(text_signature = "(self, operator)")]
     pub fn has_logical(&self, operator: &PyPauliOperator) -> bool {
         self.inner.has_logical(&operator.inner)
     }
 
+    /// Checks if the operator is in the stabilizer group.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the operator length is not the length of the code.
     #[pyo3(text_signature = "(self, operator)")]
-    pub fn has_stabilizer(&self, operator: &PyPauliOperator) -> bool {
-        self.inner.has_logical(&operator.inner)
+    pub fn has_stabilizer(&self, operator: &PyPauliOperator) -> PyResult<bool> {
+        let (x_part, z_part) = self.binary_parts(operator)?;
+        Ok(self.is_stabilizer(&x_part, &z_part))
+    }
+
+    /// Returns the logical operators implemented by an operator.
+    ///
+    /// The bits are computed from the commutation with the logicals
+    /// returned by `paired_logicals`.
+    /// They are only meaningful if the operator commutes with all stabilizers.
+    ///
+    /// Returns
+    /// -------
+    /// (BinaryVector, BinaryVector)
+    ///     The i-th X bit is 1 if the operator contains the i-th X logical
+    ///     and the i-th Z bit is 1 if the operator contains the i-th Z logical.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import Pauli, PauliOperator, steane_code
+    ///     >>> code = steane_code()
+    ///     >>> x_logicals, _ = code.paired_logicals()
+    ///     >>> positions = list(x_logicals.row(0))
+    ///     >>> operator = PauliOperator(7, positions, [Pauli.X()] * len(positions))
+    ///     >>> code.logical_action(operator)
+    ///     ([0], [])
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the operator length is not the length of the code.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn logical_action(
+        &self,
+        operator: &PyPauliOperator,
+    ) -> PyResult<(PyBinaryVector, PyBinaryVector)> {
+        let (x_part, z_part) = self.binary_parts(operator)?;
+        let (x_bits, z_bits) = self.logical_pairs(false).action_of(&x_part, &z_part);
+        Ok((x_bits.into(), z_bits.into()))
+    }
+
+    /// Classifies an operator, such as the residual of a correction.
+    ///
+    /// Returns
+    /// -------
+    /// str
+    ///     "trivial" if the operator is the identity,
+    ///     "stabilizer" if it is a non trivial stabilizer,
+    ///     "logical" if it commutes with the stabilizers
+    ///     but implements a non trivial logical operator
+    ///     and "not_in_normalizer" if it anticommutes with some stabilizers.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import Pauli, PauliOperator, steane_code
+    ///     >>> code = steane_code()
+    ///     >>> code.classify(PauliOperator(7, [0], [Pauli.X()]))
+    ///     'not_in_normalizer'
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the operator length is not the length of the code.
+    #[pyo3(text_signature = "(self, residual)")]
+    pub fn classify(&self, residual: &PyPauliOperator) -> PyResult<&'static str> {
+        let (x_part, z_part) = self.binary_parts(residual)?;
+        Ok(if x_part.is_zero() && z_part.is_zero() {
+            "trivial"
+        } else if !self.inner.has_logical(&residual.inner) {
+            "not_in_normalizer"
+        } else if self.is_stabilizer(&x_part, &z_part) {
+            "stabilizer"
+        } else {
+            "logical"
+        })
     }
 
     pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
@@ -172,6 +423,45 @@ impl PyCssCode {
     }
 }
 
+impl PyCssCode {
+    fn binary_parts(&self, operator: &PyPauliOperator) -> PyResult<(SparseBinVec, SparseBinVec)> {
+        if operator.inner.len() != self.length() {
+            return Err(PyValueError::new_err(format!(
+                "operator of length {} is incompatible with code of length {}",
+                operator.inner.len(),
+                self.length()
+            )));
+        }
+        let binary = to_symplectic(&operator.inner);
+        let length = self.length();
+        let x_positions = binary
+            .non_trivial_positions()
+            .filter(|position| *position < length);
+        let z_positions = binary
+            .non_trivial_positions()
+            .filter(|position| *position >= length)
+            .map(|position| position - length);
+        Ok((
+            SparseBinVec::new(length, x_positions.collect()),
+            SparseBinVec::new(length, z_positions.collect()),
+        ))
+    }
+
+    fn is_stabilizer(&self, x_part: &SparseBinVec, z_part: &SparseBinVec) -> bool {
+        self.x_stabilizer_space().contains(x_part) && self.z_stabilizer_space().contains(z_part)
+    }
+
+    pub(crate) fn logical_pairs(&self, minimize_weight: bool) -> PairedLogicals {
+        let x_stabilizers = self.inner.x_stabs_binary();
+        let z_stabilizers = self.inner.z_stabs_binary();
+        let mut logicals = PairedLogicals::new(x_stabilizers, z_stabilizers);
+        if minimize_weight {
+            logicals.minimize_weights(x_stabilizers, z_stabilizers);
+        }
+        logicals
+    }
+}
+
 #[pyproto]
 impl PyObjectProtocol for PyCssCode {
     fn __repr__(&self) -> String {
@@ -181,6 +471,20 @@ impl PyObjectProtocol for PyCssCode {
             self.inner.z_stabs_binary(),
         )
     }
+
+    fn __richcmp__(&self, other: PyRef<Self>, op: CompareOp) -> PyResult<bool> {
+        match op {
+            CompareOp::Eq => Ok(self.inner == other.inner),
+            CompareOp::Ne => Ok(self.inner != other.inner),
+            _ => Err(PyNotImplementedError::new_err("not implemented")),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.inner.hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 #[pyproto]
@@ -0,0 +1,340 @@
+use super::pauli_from_parts;
+use crate::css_code::PyCssCode;
+use crate::packed::PackedMatrix;
+use crate::sparse::PyBinaryVector;
+use crate::PyLinearCode;
+use bincode::{deserialize, serialize};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::PyObjectProtocol;
+use pyo3::ToPyObject;
+use serde::{Deserialize, Serialize};
+use sparse_bin_mat::{SparseBinMat, SparseBinVec};
+use std::collections::HashMap;
+
+/// A decoder storing a minimum weight correction for each syndrome.
+///
+/// The table is built by enumerating errors by increasing weight
+/// until every reachable syndrome is found or the maximum weight is reached.
+/// For a CSS code, X and Z errors are decoded independently
+/// with one table for each type.
+///
+/// Parameters
+/// ----------
+/// code : LinearCode or CssCode
+///     The code to decode.
+/// max_weight : Optional[int]
+///     The maximum weight of the enumerated errors.
+///     If omitted, errors are enumerated until all syndromes are found.
+///
+/// Example
+/// -------
+///     >>> from qecstruct import BinaryVector, LookupDecoder, hamming_code
+///     >>> code = hamming_code()
+///     >>> decoder = LookupDecoder(code)
+///     >>> error = BinaryVector(7, [4])
+///     >>> decoder.decode(code.syndrome_of(error)) == error
+///     True
+///
+/// Caution
+/// -------
+/// The number of enumerated errors grows exponentially with the weight.
+/// This is only practical for small codes.
+///
+/// Raises
+/// ------
+/// TypeError
+///     If the code is not a LinearCode or a CssCode.
+#[pyclass(name = "LookupDecoder", module = "qecstruct")]
+#[pyo3(text_signature = "(code, max_weight=None)")]
+pub struct PyLookupDecoder {
+    tables: Tables,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Tables {
+    Classical(SyndromeTable),
+    Css {
+        // Decodes the syndrome of the Z stabilizers.
+        x_errors: SyndromeTable,
+        // Decodes the syndrome of the X stabilizers.
+        z_errors: SyndromeTable,
+    },
+}
+
+impl Tables {
+    // Checks that unpickled tables can't make decoding panic.
+    fn validate(&self) -> PyResult<()> {
+        match self {
+            Tables::Classical(table) => table.validate(),
+            Tables::Css { x_errors, z_errors } => {
+                x_errors.validate()?;
+                z_errors.validate()?;
+                if x_errors.length() != z_errors.length() {
+                    return Err(PyValueError::new_err(format!(
+                        "X table of length {} but Z table of length {}",
+                        x_errors.length(),
+                        z_errors.length()
+                    )));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[pymethods]
+impl PyLookupDecoder {
+    #[new]
+    #[args(code = "None", max_weight = "None")]
+    pub fn new(py: Python, code: Option<PyObject>, max_weight: Option<usize>) -> PyResult<Self> {
+        let tables = match code {
+            None => Tables::Classical(SyndromeTable::empty()),
+            Some(code) => {
+                let code = code.as_ref(py);
+                if let Ok(code) = code.extract::<PyRef<PyLinearCode>>() {
+                    Tables::Classical(SyndromeTable::new(
+                        code.inner.parity_check_matrix(),
+                        max_weight,
+                    ))
+                } else if let Ok(code) = code.extract::<PyRef<PyCssCode>>() {
+                    Tables::Css {
+                        x_errors: SyndromeTable::new(code.inner.z_stabs_binary(), max_weight),
+                        z_errors: SyndromeTable::new(code.inner.x_stabs_binary(), max_weight),
+                    }
+                } else {
+                    return Err(PyTypeError::new_err(
+                        "code must be a LinearCode or a CssCode",
+                    ));
+                }
+            }
+        };
+        Ok(Self { tables })
+    }
+
+    /// Returns a minimum weight correction for the given syndrome.
+    ///
+    /// Parameters
+    /// ----------
+    /// syndrome : BinaryVector or (BinaryVector, BinaryVector)
+    ///     For a linear code, the syndrome of the error.
+    ///     For a CSS code, the X and Z syndromes as returned by `CssCode.syndrome_of`.
+    ///
+    /// Returns
+    /// -------
+    /// Optional[BinaryVector or PauliOperator]
+    ///     The correction or None if no error up to the maximum weight
+    ///     has this syndrome.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the syndrome length is not the number of checks.
+    /// TypeError
+    ///     If the syndrome type doesn't match the code type.
+    #[pyo3(text_signature = "(self, syndrome)")]
+    pub fn decode(&self, py: Python, syndrome: &PyAny) -> PyResult<Option<PyObject>> {
+        match &self.tables {
+            Tables::Classical(table) => {
+                let syndrome = syndrome.extract::<PyRef<PyBinaryVector>>()?;
+                Ok(table.correction_of(&syndrome.inner)?.map(|correction| {
+                    PyBinaryVector::from(SparseBinVec::new(table.length(), correction)).into_py(py)
+                }))
+            }
+            Tables::Css { x_errors, z_errors } => {
+                let (x_syndrome, z_syndrome) =
+                    syndrome.extract::<(PyRef<PyBinaryVector>, PyRef<PyBinaryVector>)>()?;
+                let x_correction = x_errors.correction_of(&z_syndrome.inner)?;
+                let z_correction = z_errors.correction_of(&x_syndrome.inner)?;
+                Ok(x_correction
+                    .zip(z_correction)
+                    .map(|(x, z)| pauli_from_parts(x_errors.length(), &x, &z).into_py(py)))
+            }
+        }
+    }
+
+    /// The maximum weight of the enumerated errors.
+    ///
+    /// This is less than the requested maximum weight
+    /// if all syndromes are found with lower weight errors.
+    #[pyo3(text_signature = "(self)")]
+    pub fn max_weight(&self) -> usize {
+        match &self.tables {
+            Tables::Classical(table) => table.max_weight,
+            Tables::Css { x_errors, z_errors } => x_errors.max_weight.max(z_errors.max_weight),
+        }
+    }
+
+    /// The number of syndromes stored in the table.
+    ///
+    /// For a CSS code, this is the sum of the sizes of the X and Z tables.
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_syndromes(&self) -> usize {
+        match &self.tables {
+            Tables::Classical(table) => table.corrections.len(),
+            Tables::Css { x_errors, z_errors } => {
+                x_errors.corrections.len() + z_errors.corrections.len()
+            }
+        }
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                let tables: Tables = deserialize(s.as_bytes())
+                    .map_err(|error| PyValueError::new_err(error.to_string()))?;
+                tables.validate()?;
+                self.tables = tables;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &serialize(&self.tables).unwrap()).to_object(py))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PyLookupDecoder {
+    fn __repr__(&self) -> String {
+        format!(
+            "LookupDecoder with {} syndromes up to weight {}",
+            self.num_syndromes(),
+            self.max_weight()
+        )
+    }
+}
+
+/// A map from packed syndromes to minimum weight errors.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SyndromeTable {
+    // The transposed checks, that is each row is the syndrome of a single bit flip.
+    bit_syndromes: PackedMatrix,
+    // The weight of the last enumerated errors.
+    max_weight: usize,
+    corrections: HashMap<Vec<u64>, Vec<usize>>,
+}
+
+impl SyndromeTable {
+    fn new(checks: &SparseBinMat, max_weight: Option<usize>) -> Self {
+        let bit_syndromes = PackedMatrix::from_sparse(&checks.transposed());
+        let length = checks.number_of_columns();
+        let max_weight = max_weight.unwrap_or(length).min(length);
+        let num_syndromes = 1usize
+            .checked_shl(bit_syndromes.rank() as u32)
+            .unwrap_or(usize::MAX);
+        let mut table = Self {
+            bit_syndromes,
+            max_weight: 0,
+            corrections: HashMap::new(),
+        };
+        let mut error = Vec::with_capacity(max_weight);
+        let syndrome = table.packed_syndrome(&[]);
+        for weight in 0..=max_weight {
+            if table.corrections.len() >= num_syndromes {
+                break;
+            }
+            table.insert_errors_of_weight(weight, 0, &mut error, &syndrome);
+            table.max_weight = weight;
+        }
+        table
+    }
+
+    // The table of a code without bits used before unpickling.
+    fn empty() -> Self {
+        Self {
+            bit_syndromes: PackedMatrix::zeros(0, 0),
+            max_weight: 0,
+            corrections: HashMap::new(),
+        }
+    }
+
+    fn length(&self) -> usize {
+        self.bit_syndromes.num_rows()
+    }
+
+    fn validate(&self) -> PyResult<()> {
+        if !self.bit_syndromes.has_consistent_shape() {
+            return Err(PyValueError::new_err("inconsistent bit syndromes"));
+        }
+        let num_words = self.packed_syndrome(&[]).len();
+        for (syndrome, correction) in self.corrections.iter() {
+            if syndrome.len() != num_words {
+                return Err(PyValueError::new_err(format!(
+                    "syndrome of {} words is incompatible with {} checks",
+                    syndrome.len(),
+                    self.bit_syndromes.num_columns()
+                )));
+            }
+            if correction.windows(2).any(|pair| pair[0] >= pair[1]) {
+                return Err(PyValueError::new_err(format!(
+                    "correction {:?} is not sorted",
+                    correction
+                )));
+            }
+            if let Some(position) = correction
+                .iter()
+                .find(|position| **position >= self.length())
+            {
+                return Err(PyValueError::new_err(format!(
+                    "correction position {} is out of bound for length {}",
+                    position,
+                    self.length()
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    // Enumerates the errors extending the current error with positions
+    // starting at the given one while keeping the first syndrome found.
+    fn insert_errors_of_weight(
+        &mut self,
+        weight: usize,
+        start: usize,
+        error: &mut Vec<usize>,
+        syndrome: &[u64],
+    ) {
+        if error.len() == weight {
+            self.corrections
+                .entry(syndrome.to_vec())
+                .or_insert_with(|| error.clone());
+            return;
+        }
+        for position in start..self.length() {
+            let next_syndrome: Vec<u64> = syndrome
+                .iter()
+                .zip(self.bit_syndromes.row(position))
+                .map(|(word, flip)| word ^ flip)
+                .collect();
+            error.push(position);
+            self.insert_errors_of_weight(weight, position + 1, error, &next_syndrome);
+            error.pop();
+        }
+    }
+
+    fn packed_syndrome(&self, positions: &[usize]) -> Vec<u64> {
+        let mut syndrome = PackedMatrix::zeros(1, self.bit_syndromes.num_columns());
+        for position in positions {
+            syndrome.flip(0, *position);
+        }
+        syndrome.row(0).to_vec()
+    }
+
+    fn correction_of(&self, syndrome: &SparseBinVec) -> PyResult<Option<Vec<usize>>> {
+        if syndrome.len() != self.bit_syndromes.num_columns() {
+            return Err(PyValueError::new_err(format!(
+                "syndrome of length {} is incompatible with {} checks",
+                syndrome.len(),
+                self.bit_syndromes.num_columns()
+            )));
+        }
+        Ok(self
+            .corrections
+            .get(&self.packed_syndrome(syndrome.as_slice()))
+            .cloned())
+    }
+}
@@ -0,0 +1,320 @@
+use super::{pauli_from_parts, pauli_probabilities_of, solve};
+use crate::css_code::PyCssCode;
+use crate::packed::{Factorization, PackedMatrix};
+use crate::pauli::PyPauliOperator;
+use crate::sparse::PyBinaryVector;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::PyObjectProtocol;
+use sparse_bin_mat::{SparseBinMat, SparseBinVec};
+
+// The maximum number of generators of the enumerated group
+// such that a decoder never enumerates more than 2^30 operators.
+const MAX_NUM_GENERATORS: usize = 30;
+
+/// An exact maximum likelihood decoder for small CSS codes.
+///
+/// Since many errors have the same effect on the code, the most likely
+/// correction is the one in the logical coset with the largest probability.
+/// The probability of each coset is computed by summing the probabilities
+/// of the errors in the coset, that is by enumerating the stabilizer group.
+///
+/// Parameters
+/// ----------
+/// code : CssCode
+///     The code to decode.
+/// noise : DepolarizingNoise, BiasedNoise or BinarySymmetricChannel
+///     The noise model. A binary symmetric channel flips the X and Z parts
+///     of each qubit independently with the same probability.
+///
+/// Example
+/// -------
+///     >>> from qecstruct import *
+///     >>> code = steane_code()
+///     >>> decoder = MaximumLikelihoodDecoder(code, DepolarizingNoise(0.1))
+///     >>> error = PauliOperator(7, [2], [Pauli.Y()])
+///     >>> correction = decoder.decode(code.syndrome_of(error))
+///     >>> code.classify(error.apply(correction))
+///     'stabilizer'
+///
+/// Caution
+/// -------
+/// The decoding time is proportional to 2^(n + k) for a code
+/// with n qubits and k logical qubits.
+/// This is only practical for small codes.
+///
+/// Raises
+/// ------
+/// TypeError
+///     If the noise is not a DepolarizingNoise, a BiasedNoise
+///     or a BinarySymmetricChannel.
+/// ValueError
+///     If the code has too many stabilizers and logicals to enumerate.
+#[pyclass(name = "MaximumLikelihoodDecoder", module = "qecstruct")]
+#[pyo3(text_signature = "(code, noise)")]
+pub struct PyMaximumLikelihoodDecoder {
+    length: usize,
+    // Finds an X error from the syndrome of the Z stabilizers.
+    x_solver: Factorization,
+    // Finds a Z error from the syndrome of the X stabilizers.
+    z_solver: Factorization,
+    stabilizers: Vec<Operator>,
+    // The X logicals followed by their paired Z logicals.
+    logicals: Vec<Operator>,
+    // The probabilities of I, X, Z and Y on each qubit
+    // indexed by the X bit plus twice the Z bit.
+    pauli_probabilities: [f64; 4],
+}
+
+#[pymethods]
+impl PyMaximumLikelihoodDecoder {
+    #[new]
+    pub fn new(code: &PyCssCode, noise: &PyAny) -> PyResult<Self> {
+        let pauli_probabilities = pauli_probabilities_of(noise)?;
+        let length = code.length();
+        let x_stabilizers = code.x_stabilizer_space();
+        let z_stabilizers = code.z_stabilizer_space();
+        let (x_logicals, z_logicals) = code.logical_pairs(false).to_matrices(length);
+        let num_generators = x_stabilizers.dimension()
+            + z_stabilizers.dimension()
+            + x_logicals.number_of_rows()
+            + z_logicals.number_of_rows();
+        if num_generators > MAX_NUM_GENERATORS {
+            return Err(PyValueError::new_err(format!(
+                "code with {} stabilizer and logical generators is too large \
+                 for exact decoding (max {})",
+                num_generators, MAX_NUM_GENERATORS
+            )));
+        }
+        let stabilizers = Operator::x_type(x_stabilizers.basis_matrix())
+            .chain(Operator::z_type(z_stabilizers.basis_matrix()))
+            .collect();
+        let logicals = Operator::x_type(&x_logicals)
+            .chain(Operator::z_type(&z_logicals))
+            .collect();
+        Ok(Self {
+            length,
+            x_solver: Factorization::new(&PackedMatrix::from_sparse(code.inner.z_stabs_binary())),
+            z_solver: Factorization::new(&PackedMatrix::from_sparse(code.inner.x_stabs_binary())),
+            stabilizers,
+            logicals,
+            pauli_probabilities,
+        })
+    }
+
+    /// Returns a correction in the most likely logical coset
+    /// of errors with the given syndrome.
+    ///
+    /// Ties are broken in favor of the coset with the smallest index
+    /// as described in `coset_probabilities`.
+    ///
+    /// Parameters
+    /// ----------
+    /// syndrome : (BinaryVector, BinaryVector)
+    ///     The X and Z syndromes as returned by `CssCode.syndrome_of`.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If a syndrome length is not the number of stabilizers
+    ///     or if no error has the given syndrome.
+    #[pyo3(text_signature = "(self, syndrome)")]
+    pub fn decode(
+        &self,
+        syndrome: (PyRef<PyBinaryVector>, PyRef<PyBinaryVector>),
+    ) -> PyResult<PyPauliOperator> {
+        let (x_syndrome, z_syndrome) = syndrome;
+        let representative = self.representative(&x_syndrome.inner, &z_syndrome.inner)?;
+        let probabilities = self.probabilities_of_cosets(&representative);
+        let mut best = 0;
+        for (coset, probability) in probabilities.iter().enumerate() {
+            if *probability > probabilities[best] {
+                best = coset;
+            }
+        }
+        let correction = representative.combined_with(&self.logicals, best);
+        Ok(pauli_from_parts(
+            self.length,
+            &unpack(&correction.x, self.length),
+            &unpack(&correction.z, self.length),
+        ))
+    }
+
+    /// Returns the relative probabilities of the logical cosets
+    /// of errors with the given syndrome.
+    ///
+    /// The i-th coset is obtained by applying the paired logicals
+    /// of `CssCode.paired_logicals` to a fixed error with the given syndrome.
+    /// The j-th X logical is applied if the j-th bit of i is 1
+    /// and the j-th Z logical is applied if the (k + j)-th bit is 1
+    /// where k is the number of logical qubits.
+    ///
+    /// Parameters
+    /// ----------
+    /// syndrome : (BinaryVector, BinaryVector)
+    ///     The X and Z syndromes as returned by `CssCode.syndrome_of`.
+    ///
+    /// Returns
+    /// -------
+    /// List[float]
+    ///     The 4^k probabilities normalized such that they sum to 1.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If a syndrome length is not the number of stabilizers
+    ///     or if no error has the given syndrome.
+    #[pyo3(text_signature = "(self, syndrome)")]
+    pub fn coset_probabilities(
+        &self,
+        syndrome: (PyRef<PyBinaryVector>, PyRef<PyBinaryVector>),
+    ) -> PyResult<Vec<f64>> {
+        let (x_syndrome, z_syndrome) = syndrome;
+        let representative = self.representative(&x_syndrome.inner, &z_syndrome.inner)?;
+        let mut probabilities = self.probabilities_of_cosets(&representative);
+        let total: f64 = probabilities.iter().sum();
+        if total > 0.0 {
+            probabilities
+                .iter_mut()
+                .for_each(|probability| *probability /= total);
+        }
+        Ok(probabilities)
+    }
+}
+
+impl PyMaximumLikelihoodDecoder {
+    // Returns an arbitrary error with the given syndromes.
+    fn representative(
+        &self,
+        x_syndrome: &SparseBinVec,
+        z_syndrome: &SparseBinVec,
+    ) -> PyResult<Operator> {
+        let x_part = solve(&self.x_solver, z_syndrome, "Z")?;
+        let z_part = solve(&self.z_solver, x_syndrome, "X")?;
+        Ok(Operator {
+            x: pack(&x_part, self.length),
+            z: pack(&z_part, self.length),
+        })
+    }
+
+    fn probabilities_of_cosets(&self, representative: &Operator) -> Vec<f64> {
+        // The probability of an operator is the product of the probabilities
+        // of its Paulis on each qubit, that is the product of the powers
+        // of the Pauli probabilities to the number of qubits with each Pauli.
+        let powers: Vec<Vec<f64>> = self
+            .pauli_probabilities
+            .iter()
+            .map(|probability| {
+                (0..=self.length)
+                    .map(|count| probability.powi(count as i32))
+                    .collect()
+            })
+            .collect();
+        let probability_of = |operator: &Operator| -> f64 {
+            operator
+                .pauli_counts(self.length)
+                .iter()
+                .zip(powers.iter())
+                .map(|(count, powers)| powers[*count])
+                .product()
+        };
+        (0..1usize << self.logicals.len())
+            .map(|coset| {
+                let mut operator = representative.combined_with(&self.logicals, coset);
+                let mut probability = probability_of(&operator);
+                // Visits the stabilizer group in Gray code order such that
+                // each step multiplies by a single generator.
+                for step in 1..1usize << self.stabilizers.len() {
+                    operator.multiply_with(&self.stabilizers[step.trailing_zeros() as usize]);
+                    probability += probability_of(&operator);
+                }
+                probability
+            })
+            .collect()
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PyMaximumLikelihoodDecoder {
+    fn __repr__(&self) -> String {
+        let [_, x_probability, z_probability, y_probability] = self.pauli_probabilities;
+        format!(
+            "MaximumLikelihoodDecoder with X, Y and Z probabilities {}, {} and {}",
+            x_probability, y_probability, z_probability
+        )
+    }
+}
+
+// A Pauli operator with packed X and Z parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Operator {
+    x: Vec<u64>,
+    z: Vec<u64>,
+}
+
+impl Operator {
+    fn x_type(matrix: &SparseBinMat) -> impl Iterator<Item = Self> {
+        let packed = PackedMatrix::from_sparse(matrix);
+        (0..packed.num_rows()).map(move |row| Self {
+            x: packed.row(row).to_vec(),
+            z: vec![0; packed.row(row).len()],
+        })
+    }
+
+    fn z_type(matrix: &SparseBinMat) -> impl Iterator<Item = Self> {
+        Self::x_type(matrix).map(|operator| Self {
+            x: operator.z,
+            z: operator.x,
+        })
+    }
+
+    // Returns the number of qubits with each Pauli
+    // indexed by the X bit plus twice the Z bit.
+    fn pauli_counts(&self, length: usize) -> [usize; 4] {
+        let mut counts = [0; 4];
+        for (x, z) in self.x.iter().zip(self.z.iter()) {
+            counts[1] += (x & !z).count_ones() as usize;
+            counts[2] += (!x & z).count_ones() as usize;
+            counts[3] += (x & z).count_ones() as usize;
+        }
+        counts[0] = length - counts[1] - counts[2] - counts[3];
+        counts
+    }
+
+    fn multiply_with(&mut self, other: &Self) {
+        xor_into(&mut self.x, &other.x);
+        xor_into(&mut self.z, &other.z);
+    }
+
+    // Returns the product of self with the generators
+    // selected by the bits of the mask.
+    fn combined_with(&self, generators: &[Self], mask: usize) -> Self {
+        let mut operator = self.clone();
+        for (index, generator) in generators.iter().enumerate() {
+            if (mask >> index) & 1 == 1 {
+                operator.multiply_with(generator);
+            }
+        }
+        operator
+    }
+}
+
+fn xor_into(target: &mut [u64], source: &[u64]) {
+    for (word, flip) in target.iter_mut().zip(source) {
+        *word ^= flip;
+    }
+}
+
+fn pack(positions: &[usize], length: usize) -> Vec<u64> {
+    let mut packed = PackedMatrix::zeros(1, length);
+    for position in positions {
+        packed.flip(0, *position);
+    }
+    packed.row(0).to_vec()
+}
+
+fn unpack(words: &[u64], length: usize) -> Vec<usize> {
+    let mut packed = PackedMatrix::zeros(1, length);
+    packed.row_mut(0).copy_from_slice(words);
+    packed.row_positions(0)
+}
@@ -0,0 +1,82 @@
+mod lookup;
+mod ml;
+mod mps;
+mod small_set_flip;
+mod tensor_network;
+pub use lookup::PyLookupDecoder;
+pub use ml::PyMaximumLikelihoodDecoder;
+pub use small_set_flip::PySmallSetFlipDecoder;
+pub use tensor_network::PyTensorNetworkDecoder;
+
+use crate::noise::{PyBiasedNoise, PyBinarySymmetricChannel, PyDepolarizingNoise};
+use crate::packed::Factorization;
+use crate::pauli::PyPauliOperator;
+use crate::symplectic::from_symplectic;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use sparse_bin_mat::SparseBinVec;
+
+// Returns the operator with X on the x positions and Z on the z positions
+// where positions in both get a Y.
+fn pauli_from_parts(
+    length: usize,
+    x_positions: &[usize],
+    z_positions: &[usize],
+) -> PyPauliOperator {
+    let positions = x_positions
+        .iter()
+        .cloned()
+        .chain(z_positions.iter().map(|position| position + length))
+        .collect();
+    from_symplectic(&SparseBinVec::new(2 * length, positions)).into()
+}
+
+// Returns the positions of an error with the given syndrome
+// where the kind is the type of the stabilizers measuring the syndrome.
+fn solve(solver: &Factorization, syndrome: &SparseBinVec, kind: &str) -> PyResult<Vec<usize>> {
+    if syndrome.len() != solver.num_rows() {
+        return Err(PyValueError::new_err(format!(
+            "{} syndrome of length {} is incompatible with {} stabilizers",
+            kind,
+            syndrome.len(),
+            solver.num_rows()
+        )));
+    }
+    solver
+        .solve(syndrome.as_slice())
+        .ok_or_else(|| PyValueError::new_err(format!("no error has the given {} syndrome", kind)))
+}
+
+// Returns the probabilities of I, X, Z and Y on each qubit
+// indexed by the X bit plus twice the Z bit.
+fn pauli_probabilities_of(noise: &PyAny) -> PyResult<[f64; 4]> {
+    if let Ok(noise) = noise.extract::<PyRef<PyDepolarizingNoise>>() {
+        let probability = noise.error_probability();
+        Ok([
+            1.0 - probability,
+            probability / 3.0,
+            probability / 3.0,
+            probability / 3.0,
+        ])
+    } else if let Ok(noise) = noise.extract::<PyRef<PyBiasedNoise>>() {
+        let (x_probability, y_probability, z_probability) = noise.pauli_probabilities();
+        Ok([
+            1.0 - noise.error_probability(),
+            x_probability,
+            z_probability,
+            y_probability,
+        ])
+    } else if let Ok(noise) = noise.extract::<PyRef<PyBinarySymmetricChannel>>() {
+        let probability = noise.error_probability();
+        Ok([
+            (1.0 - probability) * (1.0 - probability),
+            probability * (1.0 - probability),
+            probability * (1.0 - probability),
+            probability * probability,
+        ])
+    } else {
+        Err(PyTypeError::new_err(
+            "noise must be a DepolarizingNoise, a BiasedNoise or a BinarySymmetricChannel",
+        ))
+    }
+}
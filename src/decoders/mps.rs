@@ -0,0 +1,487 @@
+// The maximum number of sweeps of the Jacobi algorithm.
+const MAX_SWEEPS: usize = 60;
+
+// The relative overlap under which two columns are considered orthogonal
+// by the Jacobi algorithm. The error on the singular values is of the order
+// of the square of the overlap, which is more than enough for a truncation.
+const SVD_TOLERANCE: f64 = 1e-8;
+
+// The relative norm under which a column is linearly dependent
+// on the previous ones in a QR decomposition.
+const QR_TOLERANCE: f64 = 1e-13;
+
+/// A tensor of a site of a square network
+/// with legs ordered as north, east, south and west.
+///
+/// The legs on the boundary of the network have dimension 1.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct SiteTensor {
+    dimensions: [usize; 4],
+    data: Vec<f64>,
+}
+
+impl SiteTensor {
+    pub(super) fn from_fn<F>(dimensions: [usize; 4], value: F) -> Self
+    where
+        F: Fn([usize; 4]) -> f64,
+    {
+        let [north, east, south, west] = dimensions;
+        let mut data = Vec::with_capacity(north * east * south * west);
+        for n in 0..north {
+            for e in 0..east {
+                for s in 0..south {
+                    for w in 0..west {
+                        data.push(value([n, e, s, w]));
+                    }
+                }
+            }
+        }
+        Self { dimensions, data }
+    }
+
+    fn get(&self, north: usize, east: usize, south: usize, west: usize) -> f64 {
+        let [_, east_dimension, south_dimension, west_dimension] = self.dimensions;
+        self.data
+            [((north * east_dimension + east) * south_dimension + south) * west_dimension + west]
+    }
+}
+
+/// A matrix product state contracting a square network column by column
+/// from the west boundary as in the boundary MPS method
+/// of Bravyi, Suchara and Vargo.
+///
+/// Each tensor has a bond with the previous and the next rows
+/// and a physical leg given by the east legs of the last absorbed column.
+/// The bonds are truncated to the bond dimension by keeping the largest
+/// singular values.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct BoundaryMps {
+    tensors: Vec<Tensor>,
+    bond_dimension: usize,
+    // The contraction is the product of the tensors times exp(log_scale)
+    // such that large networks don't underflow.
+    log_scale: f64,
+}
+
+impl BoundaryMps {
+    pub(super) fn new(num_rows: usize, bond_dimension: usize) -> Self {
+        Self {
+            tensors: vec![Tensor::new(1, 1, 1, vec![1.0]); num_rows],
+            bond_dimension,
+            log_scale: 0.0,
+        }
+    }
+
+    /// Contracts the east legs of the state with the west legs of the column.
+    pub(super) fn absorb(&mut self, column: &[SiteTensor]) {
+        self.tensors = self
+            .tensors
+            .iter()
+            .zip(column)
+            .map(|(tensor, site)| tensor.absorb(site))
+            .collect();
+        if let Some(first) = self
+            .tensors
+            .iter()
+            .position(|tensor| tensor.right > self.bond_dimension)
+        {
+            self.canonicalize();
+            self.truncate(first + 1);
+        }
+        self.rescale();
+    }
+
+    /// Returns the contraction as a value and the log of its scale
+    /// once the east boundary is absorbed.
+    pub(super) fn value(&self) -> (f64, f64) {
+        let mut row = vec![1.0];
+        for tensor in self.tensors.iter() {
+            row = (0..tensor.right)
+                .map(|right| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(left, value)| value * tensor.get(left, 0, right))
+                        .sum()
+                })
+                .collect();
+        }
+        (row.iter().sum(), self.log_scale)
+    }
+
+    // Moves the weight of each tensor to the next one
+    // such that the tensors are isometries from the north.
+    fn canonicalize(&mut self) {
+        for row in 0..self.tensors.len() - 1 {
+            let tensor = &self.tensors[row];
+            let (q, r) = qr(&tensor.as_matrix(tensor.left * tensor.physical));
+            self.tensors[row] = Tensor::new(tensor.left, tensor.physical, q.columns, q.data);
+            let next = &self.tensors[row + 1];
+            let product = r.dot_with(&next.as_matrix(next.left));
+            self.tensors[row + 1] = Tensor::new(r.rows, next.physical, next.right, product.data);
+        }
+    }
+
+    // Truncates the bonds from the south up to the north bond of the given row
+    // assuming that the state is canonical.
+    //
+    // Only the bonds larger than the bond dimension are decomposed
+    // with an SVD. The weight is moved across the other bonds
+    // with a cheaper LQ decomposition to keep the state canonical.
+    fn truncate(&mut self, last_row: usize) {
+        for row in (last_row..self.tensors.len()).rev() {
+            let tensor = &self.tensors[row];
+            let matrix = tensor.as_matrix(tensor.left);
+            let (carry, isometry) = if tensor.left > self.bond_dimension {
+                let (u, singular_values, vt) = svd(&matrix);
+                let rank = rank_of(&singular_values).min(self.bond_dimension);
+                (
+                    u.first_columns(rank).scale_columns(&singular_values),
+                    vt.first_rows(rank),
+                )
+            } else {
+                let (q, r) = qr(&matrix.transposed());
+                (r.transposed(), q.transposed())
+            };
+            let rank = isometry.rows;
+            self.tensors[row] = Tensor::new(rank, tensor.physical, tensor.right, isometry.data);
+            let previous = &self.tensors[row - 1];
+            let product = previous
+                .as_matrix(previous.left * previous.physical)
+                .dot_with(&carry);
+            self.tensors[row - 1] =
+                Tensor::new(previous.left, previous.physical, rank, product.data);
+        }
+    }
+
+    fn rescale(&mut self) {
+        for tensor in self.tensors.iter_mut() {
+            let scale = tensor
+                .data
+                .iter()
+                .fold(0.0, |max: f64, value| max.max(value.abs()));
+            if scale > 0.0 {
+                tensor.data.iter_mut().for_each(|value| *value /= scale);
+                self.log_scale += scale.ln();
+            }
+        }
+    }
+}
+
+// The number of non negligible singular values, but at least 1
+// such that the bonds never vanish.
+fn rank_of(singular_values: &[f64]) -> usize {
+    let threshold = singular_values.first().cloned().unwrap_or(0.0) * f64::EPSILON;
+    singular_values
+        .iter()
+        .filter(|value| **value > threshold)
+        .count()
+        .max(1)
+}
+
+// A tensor with legs ordered as left bond, physical and right bond.
+#[derive(Debug, Clone, PartialEq)]
+struct Tensor {
+    left: usize,
+    physical: usize,
+    right: usize,
+    data: Vec<f64>,
+}
+
+impl Tensor {
+    fn new(left: usize, physical: usize, right: usize, data: Vec<f64>) -> Self {
+        Self {
+            left,
+            physical,
+            right,
+            data,
+        }
+    }
+
+    fn get(&self, left: usize, physical: usize, right: usize) -> f64 {
+        self.data[(left * self.physical + physical) * self.right + right]
+    }
+
+    // Contracts the physical leg with the west leg of the site
+    // and merges the bonds with the north and south legs.
+    fn absorb(&self, site: &SiteTensor) -> Self {
+        let [north, east, south, west] = site.dimensions;
+        let mut data = vec![0.0; self.left * north * east * self.right * south];
+        let mut index = 0;
+        for left in 0..self.left {
+            for n in 0..north {
+                for e in 0..east {
+                    for right in 0..self.right {
+                        for s in 0..south {
+                            data[index] = (0..west)
+                                .map(|w| self.get(left, w, right) * site.get(n, e, s, w))
+                                .sum();
+                            index += 1;
+                        }
+                    }
+                }
+            }
+        }
+        Self::new(self.left * north, east, self.right * south, data)
+    }
+
+    // Reshapes the tensor to a matrix with the given number of rows.
+    fn as_matrix(&self, rows: usize) -> Matrix {
+        Matrix::new(rows, self.data.len() / rows, self.data.clone())
+    }
+}
+
+// A dense matrix stored in row major order.
+#[derive(Debug, Clone, PartialEq)]
+struct Matrix {
+    rows: usize,
+    columns: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    fn new(rows: usize, columns: usize, data: Vec<f64>) -> Self {
+        Self {
+            rows,
+            columns,
+            data,
+        }
+    }
+
+    fn identity(length: usize) -> Self {
+        let mut identity = Self::new(length, length, vec![0.0; length * length]);
+        for index in 0..length {
+            identity.data[index * length + index] = 1.0;
+        }
+        identity
+    }
+
+    fn get(&self, row: usize, column: usize) -> f64 {
+        self.data[row * self.columns + column]
+    }
+
+    fn transposed(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for column in 0..self.columns {
+            for row in 0..self.rows {
+                data.push(self.get(row, column));
+            }
+        }
+        Self::new(self.columns, self.rows, data)
+    }
+
+    fn dot_with(&self, other: &Self) -> Self {
+        let mut data = vec![0.0; self.rows * other.columns];
+        for row in 0..self.rows {
+            for inner in 0..self.columns {
+                let value = self.get(row, inner);
+                for column in 0..other.columns {
+                    data[row * other.columns + column] += value * other.get(inner, column);
+                }
+            }
+        }
+        Self::new(self.rows, other.columns, data)
+    }
+
+    fn first_rows(&self, rows: usize) -> Self {
+        Self::new(
+            rows,
+            self.columns,
+            self.data[..rows * self.columns].to_vec(),
+        )
+    }
+
+    fn with_columns(&self, columns: &[usize]) -> Self {
+        let data = (0..self.rows)
+            .flat_map(|row| columns.iter().map(move |column| self.get(row, *column)))
+            .collect();
+        Self::new(self.rows, columns.len(), data)
+    }
+
+    fn first_columns(&self, columns: usize) -> Self {
+        let data = (0..self.rows)
+            .flat_map(|row| (0..columns).map(move |column| (row, column)))
+            .map(|(row, column)| self.get(row, column))
+            .collect();
+        Self::new(self.rows, columns, data)
+    }
+
+    fn scale_columns(mut self, scales: &[f64]) -> Self {
+        for row in 0..self.rows {
+            for (column, scale) in scales.iter().enumerate().take(self.columns) {
+                self.data[row * self.columns + column] *= scale;
+            }
+        }
+        self
+    }
+
+    fn row(&self, row: usize) -> &[f64] {
+        &self.data[row * self.columns..(row + 1) * self.columns]
+    }
+
+    fn row_norms(&self) -> Vec<f64> {
+        (0..self.rows)
+            .map(|row| dot(self.row(row), self.row(row)).sqrt())
+            .collect()
+    }
+
+    fn rotate_rows(&mut self, first: usize, second: usize, cosine: f64, sine: f64) {
+        for column in 0..self.columns {
+            let x = self.data[first * self.columns + column];
+            let y = self.data[second * self.columns + column];
+            self.data[first * self.columns + column] = cosine * x - sine * y;
+            self.data[second * self.columns + column] = sine * x + cosine * y;
+        }
+    }
+}
+
+// Returns a decomposition Q R of a matrix where the columns of Q are orthonormal
+// using the modified Gram-Schmidt process with reorthogonalization.
+//
+// The columns that are negligible once projected out of the previous ones
+// are skipped such that Q has as many columns as the numerical rank,
+// but at least 1.
+fn qr(matrix: &Matrix) -> (Matrix, Matrix) {
+    let column_at = |column: usize| -> Vec<f64> {
+        (0..matrix.rows)
+            .map(|row| matrix.get(row, column))
+            .collect()
+    };
+    let scale = (0..matrix.columns)
+        .map(|column| dot(&column_at(column), &column_at(column)).sqrt())
+        .fold(0.0, f64::max);
+    let mut basis: Vec<Vec<f64>> = Vec::new();
+    let mut coefficients: Vec<Vec<f64>> = Vec::new();
+    for column in 0..matrix.columns {
+        let mut residual = column_at(column);
+        let mut projections = vec![0.0; basis.len()];
+        for _ in 0..2 {
+            for (vector, projection) in basis.iter().zip(projections.iter_mut()) {
+                let overlap = dot(vector, &residual);
+                residual
+                    .iter_mut()
+                    .zip(vector)
+                    .for_each(|(value, basis_value)| *value -= overlap * basis_value);
+                *projection += overlap;
+            }
+        }
+        let norm = dot(&residual, &residual).sqrt();
+        if basis.len() < matrix.rows && norm > QR_TOLERANCE * scale {
+            residual.iter_mut().for_each(|value| *value /= norm);
+            basis.push(residual);
+            projections.push(norm);
+        }
+        coefficients.push(projections);
+    }
+    if basis.is_empty() {
+        let mut vector = vec![0.0; matrix.rows];
+        vector[0] = 1.0;
+        basis.push(vector);
+    }
+    let rank = basis.len();
+    let q = (0..matrix.rows)
+        .flat_map(|row| basis.iter().map(move |vector| vector[row]))
+        .collect();
+    let mut r = vec![0.0; rank * matrix.columns];
+    for (column, projections) in coefficients.iter().enumerate() {
+        for (row, projection) in projections.iter().enumerate() {
+            r[row * matrix.columns + column] = *projection;
+        }
+    }
+    (
+        Matrix::new(matrix.rows, rank, q),
+        Matrix::new(rank, matrix.columns, r),
+    )
+}
+
+fn dot(first: &[f64], second: &[f64]) -> f64 {
+    first.iter().zip(second).map(|(x, y)| x * y).sum()
+}
+
+// Returns the singular value decomposition U S V^T of a matrix
+// with the singular values in decreasing order.
+//
+// The Jacobi algorithm needs far fewer sweeps on the transpose of the
+// triangular factor of a QR decomposition of the matrix with its columns
+// sorted by decreasing norm. If M P = Q R and R^T = W S Z^T,
+// then M = (Q Z) S (P W)^T.
+fn svd(matrix: &Matrix) -> (Matrix, Vec<f64>, Matrix) {
+    if matrix.rows < matrix.columns {
+        let (u, singular_values, vt) = svd(&matrix.transposed());
+        return (vt.transposed(), singular_values, u.transposed());
+    }
+    let norms = matrix.transposed().row_norms();
+    let mut order: Vec<usize> = (0..matrix.columns).collect();
+    order.sort_by(|first, second| norms[*second].partial_cmp(&norms[*first]).unwrap());
+    let (q, r) = qr(&matrix.with_columns(&order));
+    let (w, singular_values, zt) = jacobi(&r.transposed());
+    let mut v = Matrix::new(w.rows, w.columns, vec![0.0; w.data.len()]);
+    for (row, permuted_row) in order.iter().enumerate() {
+        v.data[permuted_row * w.columns..(permuted_row + 1) * w.columns]
+            .copy_from_slice(w.row(row));
+    }
+    (
+        q.dot_with(&zt.transposed()),
+        singular_values,
+        v.transposed(),
+    )
+}
+
+// Returns the singular value decomposition U S V^T of a matrix
+// with at least as many rows as columns
+// and the singular values in decreasing order.
+//
+// This uses the one-sided Jacobi algorithm which orthogonalizes
+// the columns of the matrix with plane rotations.
+fn jacobi(matrix: &Matrix) -> (Matrix, Vec<f64>, Matrix) {
+    // The columns of U and V are stored as the rows of their transposes
+    // such that rotations act on contiguous memory.
+    let mut ut = matrix.transposed();
+    let mut vt = Matrix::identity(matrix.columns);
+    for _ in 0..MAX_SWEEPS {
+        // The squared norms are updated with each rotation
+        // and computed again at each sweep to avoid accumulating errors.
+        let mut squared_norms: Vec<f64> = (0..ut.rows)
+            .map(|row| dot(ut.row(row), ut.row(row)))
+            .collect();
+        let mut rotated = false;
+        for first in 0..ut.rows {
+            for second in first + 1..ut.rows {
+                let alpha = squared_norms[first];
+                let beta = squared_norms[second];
+                let gamma = dot(ut.row(first), ut.row(second));
+                if gamma.abs() <= SVD_TOLERANCE * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let tangent = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let cosine = 1.0 / (1.0 + tangent * tangent).sqrt();
+                ut.rotate_rows(first, second, cosine, cosine * tangent);
+                vt.rotate_rows(first, second, cosine, cosine * tangent);
+                squared_norms[first] -= tangent * gamma;
+                squared_norms[second] += tangent * gamma;
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+    let norms = ut.row_norms();
+    let mut order: Vec<usize> = (0..ut.rows).collect();
+    order.sort_by(|first, second| norms[*second].partial_cmp(&norms[*first]).unwrap());
+    let left = order
+        .iter()
+        .flat_map(|row| {
+            let norm = norms[*row];
+            ut.row(*row)
+                .iter()
+                .map(move |value| if norm > 0.0 { value / norm } else { 0.0 })
+        })
+        .collect();
+    let right = order.iter().flat_map(|row| vt.row(*row).to_vec()).collect();
+    (
+        Matrix::new(ut.rows, ut.columns, left).transposed(),
+        order.iter().map(|row| norms[*row]).collect(),
+        Matrix::new(vt.rows, vt.columns, right),
+    )
+}
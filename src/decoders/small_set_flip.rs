@@ -0,0 +1,269 @@
+use super::pauli_from_parts;
+use crate::css_code::PyCssCode;
+use crate::pauli::PyPauliOperator;
+use crate::sparse::PyBinaryVector;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::PyObjectProtocol;
+use sparse_bin_mat::{SparseBinMat, SparseBinVec};
+use std::collections::BTreeSet;
+
+// The maximum weight of a stabilizer generator
+// such that at most 2^16 small sets are enumerated per generator.
+const MAX_GENERATOR_WEIGHT: usize = 16;
+
+/// The small-set-flip decoder of Leverrier, Tillich and Zémor
+/// for hypergraph product codes.
+///
+/// X and Z errors are decoded independently. To decode X errors,
+/// the decoder repeatedly flips the subset of the support of an X stabilizer
+/// generator that maximizes the decrease of the weight of the Z syndrome
+/// relative to the size of the subset. It stops when the syndrome is zero or
+/// gets stuck when no subset decreases the weight of the syndrome.
+/// Z errors are decoded the same way by exchanging the roles of the stabilizers.
+///
+/// The decoder corrects errors of weight up to a constant fraction
+/// of the distance for hypergraph products of good expanding codes,
+/// but it works on the stabilizers of any CSS code.
+///
+/// Parameters
+/// ----------
+/// code : CssCode
+///     The code to decode.
+///
+/// Example
+/// -------
+///     >>> from qecstruct import *
+///     >>> code = hypergraph_product(hamming_code(), hamming_code())
+///     >>> decoder = SmallSetFlipDecoder(code)
+///     >>> error = PauliOperator(len(code), [5, 20], [Pauli.X(), Pauli.Z()])
+///     >>> correction, stuck = decoder.decode(code.syndrome_of(error))
+///     >>> stuck, code.classify(error.apply(correction))
+///     (False, 'trivial')
+///
+/// Raises
+/// ------
+/// ValueError
+///     If a stabilizer generator has a weight larger than 16.
+#[pyclass(name = "SmallSetFlipDecoder", module = "qecstruct")]
+#[pyo3(text_signature = "(code)")]
+pub struct PySmallSetFlipDecoder {
+    length: usize,
+    // Decodes the syndrome of the Z stabilizers
+    // with subsets of the X stabilizers.
+    x_errors: SmallSetFlip,
+    // Decodes the syndrome of the X stabilizers
+    // with subsets of the Z stabilizers.
+    z_errors: SmallSetFlip,
+}
+
+#[pymethods]
+impl PySmallSetFlipDecoder {
+    #[new]
+    pub fn new(code: &PyCssCode) -> PyResult<Self> {
+        let x_stabilizers = code.inner.x_stabs_binary();
+        let z_stabilizers = code.inner.z_stabs_binary();
+        Ok(Self {
+            length: code.length(),
+            x_errors: SmallSetFlip::new(z_stabilizers, x_stabilizers)?,
+            z_errors: SmallSetFlip::new(x_stabilizers, z_stabilizers)?,
+        })
+    }
+
+    /// Returns a correction for the given syndrome and
+    /// a flag telling if the decoder got stuck.
+    ///
+    /// If the decoder gets stuck, the correction contains the flips
+    /// done so far and doesn't have the given syndrome.
+    ///
+    /// Parameters
+    /// ----------
+    /// syndrome : (BinaryVector, BinaryVector)
+    ///     The X and Z syndromes as returned by `CssCode.syndrome_of`.
+    ///
+    /// Returns
+    /// -------
+    /// (PauliOperator, bool)
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If a syndrome length is not the number of stabilizers.
+    #[pyo3(text_signature = "(self, syndrome)")]
+    pub fn decode(
+        &self,
+        syndrome: (PyRef<PyBinaryVector>, PyRef<PyBinaryVector>),
+    ) -> PyResult<(PyPauliOperator, bool)> {
+        let (x_syndrome, z_syndrome) = syndrome;
+        let (x_correction, x_stuck) = self.x_errors.decode(&z_syndrome.inner, "Z")?;
+        let (z_correction, z_stuck) = self.z_errors.decode(&x_syndrome.inner, "X")?;
+        Ok((
+            pauli_from_parts(self.length, &x_correction, &z_correction),
+            x_stuck || z_stuck,
+        ))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PySmallSetFlipDecoder {
+    fn __repr__(&self) -> String {
+        format!("SmallSetFlipDecoder for code of length {}", self.length)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct SmallSetFlip {
+    // The checks of each bit.
+    bit_checks: SparseBinMat,
+    // The bits of each check.
+    checks: SparseBinMat,
+    // The generators containing each bit.
+    bit_generators: SparseBinMat,
+    // The small sets are the subsets of the support of each generator.
+    generators: SparseBinMat,
+}
+
+// A subset of the support of a generator with the decrease
+// of the syndrome weight if it is flipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Flip {
+    generator: usize,
+    mask: usize,
+    decrease: usize,
+}
+
+impl Flip {
+    fn size(&self) -> usize {
+        self.mask.count_ones() as usize
+    }
+
+    fn is_better_than(&self, other: &Self) -> bool {
+        self.decrease * other.size() > other.decrease * self.size()
+    }
+}
+
+impl SmallSetFlip {
+    fn new(checks: &SparseBinMat, generators: &SparseBinMat) -> PyResult<Self> {
+        if let Some(generator) = generators
+            .rows()
+            .position(|generator| generator.weight() > MAX_GENERATOR_WEIGHT)
+        {
+            return Err(PyValueError::new_err(format!(
+                "generator {} has weight {} which is more than {}",
+                generator,
+                generators.row(generator).unwrap().weight(),
+                MAX_GENERATOR_WEIGHT
+            )));
+        }
+        Ok(Self {
+            bit_checks: checks.transposed(),
+            checks: checks.clone(),
+            bit_generators: generators.transposed(),
+            generators: generators.clone(),
+        })
+    }
+
+    // Returns the positions of the correction and true if the decoder got stuck.
+    fn decode(&self, syndrome: &SparseBinVec, kind: &str) -> PyResult<(Vec<usize>, bool)> {
+        if syndrome.len() != self.checks.number_of_rows() {
+            return Err(PyValueError::new_err(format!(
+                "{} syndrome of length {} is incompatible with {} stabilizers",
+                kind,
+                syndrome.len(),
+                self.checks.number_of_rows()
+            )));
+        }
+        let mut unsatisfied = vec![false; syndrome.len()];
+        for check in syndrome.non_trivial_positions() {
+            unsatisfied[check] = true;
+        }
+        let mut correction = vec![false; self.bit_checks.number_of_rows()];
+        let mut parities = vec![false; syndrome.len()];
+        while unsatisfied.iter().any(|check| *check) {
+            let flip = match self.best_flip(&unsatisfied, &mut parities) {
+                Some(flip) => flip,
+                None => return Ok((positions_of(&correction), true)),
+            };
+            for bit in self.bits_of(&flip) {
+                correction[bit] = !correction[bit];
+                for check in self.bit_checks.row(bit).unwrap().non_trivial_positions() {
+                    unsatisfied[check] = !unsatisfied[check];
+                }
+            }
+        }
+        Ok((positions_of(&correction), false))
+    }
+
+    // Returns the flip with the largest decrease per flipped bit
+    // among the generators close to an unsatisfied check.
+    fn best_flip(&self, unsatisfied: &[bool], parities: &mut [bool]) -> Option<Flip> {
+        let candidates: BTreeSet<usize> = unsatisfied
+            .iter()
+            .enumerate()
+            .filter(|(_, is_unsatisfied)| **is_unsatisfied)
+            .flat_map(|(check, _)| self.checks.row(check).unwrap().as_slice().to_vec())
+            .flat_map(|bit| self.bit_generators.row(bit).unwrap().as_slice().to_vec())
+            .collect();
+        let mut best: Option<Flip> = None;
+        for generator in candidates {
+            let support = self.generators.row(generator).unwrap();
+            let support = support.as_slice();
+            // Visits the subsets in Gray code order such that each step
+            // flips a single bit and updates the syndrome weight change.
+            let mut mask = 0;
+            let mut change = 0;
+            for step in 1..1usize << support.len() {
+                let position = step.trailing_zeros() as usize;
+                mask ^= 1 << position;
+                for check in self
+                    .bit_checks
+                    .row(support[position])
+                    .unwrap()
+                    .non_trivial_positions()
+                {
+                    parities[check] = !parities[check];
+                    change += if parities[check] == unsatisfied[check] {
+                        1
+                    } else {
+                        -1
+                    };
+                }
+                if change > 0 {
+                    let flip = Flip {
+                        generator,
+                        mask,
+                        decrease: change as usize,
+                    };
+                    if best.is_none_or(|best| flip.is_better_than(&best)) {
+                        best = Some(flip);
+                    }
+                }
+            }
+            for bit in support {
+                for check in self.bit_checks.row(*bit).unwrap().non_trivial_positions() {
+                    parities[check] = false;
+                }
+            }
+        }
+        best
+    }
+
+    fn bits_of(&self, flip: &Flip) -> Vec<usize> {
+        self.generators
+            .row(flip.generator)
+            .unwrap()
+            .non_trivial_positions()
+            .enumerate()
+            .filter(|(index, _)| (flip.mask >> index) & 1 == 1)
+            .map(|(_, bit)| bit)
+            .collect()
+    }
+}
+
+fn positions_of(bits: &[bool]) -> Vec<usize> {
+    bits.iter()
+        .enumerate()
+        .filter(|(_, bit)| **bit)
+        .map(|(position, _)| position)
+        .collect()
+}
@@ -1,13 +1,32 @@
 use pyo3::prelude::*;
 
 mod linear_code;
-use linear_code::{hamming_code, random_regular_code, repetition_code, PyLinearCode};
+use linear_code::{
+    irregular_code, progressive_edge_growth_code, random_regular_code, repetition_code,
+    PyLinearCode,
+};
+
+mod catalog;
+use catalog::{
+    bacon_shor_code, bch_code, five_qubit_code, four_two_two_code, golay_code, hamming_code,
+    planar_surface_code, quantum_reed_muller_code, reed_muller_code, reed_muller_css_code,
+    reed_muller_triorthogonal_code, subsystem_bacon_shor_code, triorthogonal_code,
+};
 
 mod css_code;
 use css_code::{hypergraph_product, shor_code, steane_code, PyCssCode};
 
+mod decoders;
+use decoders::{
+    PyLookupDecoder, PyMaximumLikelihoodDecoder, PySmallSetFlipDecoder, PyTensorNetworkDecoder,
+};
+
+mod logicals;
+
 mod noise;
-use noise::PyBinarySymmetricChannel;
+use noise::{PyBiasedNoise, PyBinarySymmetricChannel, PyDepolarizingNoise};
+
+mod packed;
 
 mod pauli;
 use crate::pauli::{PyPauli, PyPauliOperator};
@@ -16,18 +35,42 @@ mod randomness;
 use randomness::PyRng;
 
 mod sparse;
-use sparse::{PyBinaryMatrix, PyBinaryVector};
+use sparse::{
+    to_dense, PyBinaryMatrix, PyBinarySubspace, PyBinaryVector, PyDenseBinaryMatrix,
+    PyFactorization,
+};
+
+mod stabilizer_code;
+use stabilizer_code::PyStabilizerCode;
+
+mod subsystem_code;
+use subsystem_code::PySubsystemCode;
+
+mod symplectic;
+
+mod tanner_graph;
 
 /// Sparse data structure for classical and quantum error correction.
 #[pymodule]
 fn qecstruct(_py: Python, module: &PyModule) -> PyResult<()> {
     module.add_class::<PyLinearCode>()?;
     module.add_class::<PyBinarySymmetricChannel>()?;
+    module.add_class::<PyDepolarizingNoise>()?;
+    module.add_class::<PyBiasedNoise>()?;
+    module.add_class::<PyLookupDecoder>()?;
+    module.add_class::<PyMaximumLikelihoodDecoder>()?;
+    module.add_class::<PyTensorNetworkDecoder>()?;
+    module.add_class::<PySmallSetFlipDecoder>()?;
     module.add_class::<PyBinaryMatrix>()?;
     module.add_class::<PyBinaryVector>()?;
+    module.add_class::<PyBinarySubspace>()?;
+    module.add_class::<PyDenseBinaryMatrix>()?;
+    module.add_class::<PyFactorization>()?;
     module.add_class::<PyPauli>()?;
     module.add_class::<PyPauliOperator>()?;
     module.add_class::<PyCssCode>()?;
+    module.add_class::<PyStabilizerCode>()?;
+    module.add_class::<PySubsystemCode>()?;
     module.add_class::<PyRng>()?;
 
     /// Samples a random regular codes.
@@ -70,11 +113,247 @@ fn qecstruct(_py: Python, module: &PyModule) -> PyResult<()> {
         random_regular_code(num_bits, num_checks, bit_degree, check_degree, rng)
     }
 
-    /// Returns an instance of the Hamming code.
+    /// Samples a code using the progressive edge growth algorithm.
+    ///
+    /// Edges are added one bit at a time, in increasing order of degree.
+    /// Each new edge connects the bit to one of the checks that is the farthest
+    /// away from it in the current Tanner graph, preferring checks
+    /// with the smallest degree.
+    /// This greedily maximizes the girth of the resulting Tanner graph.
+    ///
+    /// The check degrees can't be specified.
+    /// They are only kept as balanced as possible by the preference
+    /// for least connected checks.
+    ///
+    /// Parameters
+    /// ----------
+    /// num_checks: int
+    ///     The number of checks in the code.
+    /// bit_degrees: Seq[int]
+    ///     The number of checks connected to each bit.
+    ///     The length of the sequence is the number of bits in the code.
+    /// rng: Rng
+    ///     The random number generator used to break ties between checks.
+    ///
+    /// Returns
+    /// -------
+    /// LinearCode
+    ///     A code with the given bit degrees.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If a bit degree is larger than the number of checks.
+    #[pyfn(module)]
+    #[pyo3(
+        name = "progressive_edge_growth_code",
+        text_signature = "(num_checks, bit_degrees, rng)"
+    )]
+    fn py_progressive_edge_growth_code(
+        num_checks: usize,
+        bit_degrees: Vec<usize>,
+        rng: &mut PyRng,
+    ) -> PyResult<PyLinearCode> {
+        progressive_edge_growth_code(num_checks, bit_degrees, rng)
+    }
+
+    /// Samples a random code from edge-perspective degree distributions.
+    ///
+    /// The distributions are given as the coefficients of the polynomials
+    /// `λ(x) = Σ λ_d x^(d-1)` and `ρ(x) = Σ ρ_d x^(d-1)` where
+    /// `λ_d` (`ρ_d`) is the fraction of edges connected to bits (checks)
+    /// of degree `d`.
+    /// The number of nodes of each degree are rounded to the closest integers
+    /// such that both sides of the Tanner graph have the same number of edges.
+    ///
+    /// Parameters
+    /// ----------
+    /// num_bits: int
+    ///     The number of bits in the code.
+    /// bit_distribution: Seq[float]
+    ///     The coefficients `[λ_1, λ_2, ...]` of the bit degree distribution.
+    /// check_distribution: Seq[float]
+    ///     The coefficients `[ρ_1, ρ_2, ...]` of the check degree distribution.
+    /// rng: Rng
+    ///     The random number generator.
+    /// avoid_repeated_edges: bool, default = True
+    ///     If False, repeated edges between a bit and a check cancel out.
+    /// avoid_four_cycles: bool, default = False
+    ///     If True, the Tanner graph of the code has no cycle of length 4.
+    /// max_attempts: int, default = 100
+    ///     The number of times the sampling is restarted when
+    ///     the constraints can't be satisfied.
+    ///
+    /// Returns
+    /// -------
+    /// LinearCode
+    ///     A random code with the given degree distributions.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If a distribution has negative or non-finite coefficients or doesn't sum to 1,
+    ///     or if no code satisfying the constraints is found.
+    ///
+    /// Example
+    /// -------
+    /// Sample a code where half of the edges are connected to degree 2 bits
+    /// and the other half to degree 3 bits while all checks have degree 6.
+    ///
+    ///     >>> from qecstruct import irregular_code, Rng
+    ///     >>> code = irregular_code(100, [0, 0.5, 0.5], [0, 0, 0, 0, 0, 1], Rng(42))
+    #[pyfn(
+        module,
+        avoid_repeated_edges = "true",
+        avoid_four_cycles = "false",
+        max_attempts = "100"
+    )]
+    #[pyo3(
+        name = "irregular_code",
+        text_signature = "(num_bits, bit_distribution, check_distribution, rng, avoid_repeated_edges=True, avoid_four_cycles=False, max_attempts=100)"
+    )]
+    fn py_irregular_code(
+        num_bits: usize,
+        bit_distribution: Vec<f64>,
+        check_distribution: Vec<f64>,
+        rng: &mut PyRng,
+        avoid_repeated_edges: bool,
+        avoid_four_cycles: bool,
+        max_attempts: usize,
+    ) -> PyResult<PyLinearCode> {
+        irregular_code(
+            num_bits,
+            &bit_distribution,
+            &check_distribution,
+            rng,
+            avoid_repeated_edges,
+            avoid_four_cycles,
+            max_attempts,
+        )
+    }
+
+    /// Returns an instance of the Hamming code of the given order.
+    ///
+    /// The Hamming code of order `r` has length `2^r - 1`
+    /// and encodes `2^r - r - 1` bits with distance 3.
+    /// The columns of its parity check matrix are the binary representations
+    /// of the integers from 1 to `2^r - 1`.
+    ///
+    /// Arguments
+    /// ---------
+    /// order : int, default = 3
+    ///     The number of checks. Must be between 2 and 20.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the order is not in the valid range.
+    #[pyfn(module, order = "3")]
+    #[pyo3(name = "hamming_code", text_signature = "(order=3)")]
+    pub fn py_hamming_code(order: usize) -> PyResult<PyLinearCode> {
+        hamming_code(order)
+    }
+
+    /// Returns a narrow-sense primitive binary BCH code.
+    ///
+    /// The code has length `2^m - 1` and its generator polynomial is
+    /// the least common multiple of the minimal polynomials of
+    /// `α, α^2, ..., α^(d - 1)` where `α` is a primitive element
+    /// of the field with `2^m` elements and `d` the designed distance.
+    /// The minimal distance of the code is at least the designed distance.
+    ///
+    /// Arguments
+    /// ---------
+    /// order : int
+    ///     The degree `m` of the field extension. Must be between 2 and 16.
+    /// designed_distance : int
+    ///     The designed distance `d` of the code.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import bch_code
+    ///     >>> code = bch_code(4, 5)
+    ///     >>> len(code), code.dimension(), code.minimal_distance()
+    ///     (15, 7, 5)
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the order or the designed distance are not in the valid range.
+    #[pyfn(module)]
+    #[pyo3(name = "bch_code", text_signature = "(order, designed_distance)")]
+    pub fn py_bch_code(order: usize, designed_distance: usize) -> PyResult<PyLinearCode> {
+        bch_code(order, designed_distance)
+    }
+
+    /// Returns the Reed-Muller code RM(r, m).
+    ///
+    /// The codewords are the evaluations on all points of `F_2^m`
+    /// of the polynomials in `m` variables of degree at most `r`.
+    /// The code has length `2^m`, dimension `sum(binom(m, i) for i <= r)`
+    /// and distance `2^(m - r)`.
+    ///
+    /// Arguments
+    /// ---------
+    /// degree : int
+    ///     The maximal degree `r` of the polynomials.
+    /// num_variables : int
+    ///     The number of variables `m`. Must be at most 12.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import reed_muller_code
+    ///     >>> code = reed_muller_code(1, 3)
+    ///     >>> len(code), code.dimension(), code.minimal_distance()
+    ///     (8, 4, 4)
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the degree is larger than the number of variables
+    ///     or if the number of variables is too large.
+    #[pyfn(module)]
+    #[pyo3(name = "reed_muller_code", text_signature = "(degree, num_variables)")]
+    pub fn py_reed_muller_code(degree: usize, num_variables: usize) -> PyResult<PyLinearCode> {
+        reed_muller_code(degree, num_variables)
+    }
+
+    /// Returns the binary Golay code.
+    ///
+    /// Arguments
+    /// ---------
+    /// extended : bool, default = True
+    ///     If True, returns the self-dual [24, 12, 8] extended Golay code.
+    ///     Else, returns the perfect [23, 12, 7] Golay code.
+    #[pyfn(module, extended = "true")]
+    #[pyo3(name = "golay_code", text_signature = "(extended=True)")]
+    pub fn py_golay_code(extended: bool) -> PyLinearCode {
+        golay_code(extended)
+    }
+
+    /// Returns a binary matrix, a binary vector or a sequence
+    /// of binary vectors as a numpy array of uint8.
+    ///
+    /// A sequence of vectors is converted to a 2D array
+    /// where each row is a vector.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryVector, to_dense
+    ///     >>> to_dense([BinaryVector(3, [0]), BinaryVector(3, [1, 2])])
+    ///     array([[1, 0, 0],
+    ///            [0, 1, 1]], dtype=uint8)
+    ///
+    /// Raises
+    /// ------
+    /// TypeError
+    ///     If the object can't be converted.
+    /// ValueError
+    ///     If the vectors in a sequence have different lengths.
     #[pyfn(module)]
-    #[pyo3(name = "hamming_code", text_signature = "")]
-    pub fn py_hamming_code() -> PyLinearCode {
-        hamming_code()
+    #[pyo3(name = "to_dense", text_signature = "(object)")]
+    pub fn py_to_dense(py: Python, object: &PyAny) -> PyResult<PyObject> {
+        to_dense(py, object)
     }
 
     /// Returns an instance of the repetition code.
@@ -104,6 +383,241 @@ fn qecstruct(_py: Python, module: &PyModule) -> PyResult<()> {
         shor_code()
     }
 
+    /// Returns the [[5, 1, 3]] perfect code.
+    ///
+    /// The stabilizers are the cyclic shifts of XZZXI.
+    #[pyfn(module)]
+    #[pyo3(name = "five_qubit_code", text_signature = "")]
+    pub fn py_five_qubit_code() -> PyStabilizerCode {
+        five_qubit_code()
+    }
+
+    /// Returns the [[4, 2, 2]] code.
+    ///
+    /// The stabilizers are XXXX and ZZZZ.
+    #[pyfn(module)]
+    #[pyo3(name = "four_two_two_code", text_signature = "")]
+    pub fn py_four_two_two_code() -> PyCssCode {
+        four_two_two_code()
+    }
+
+    /// Returns the stabilizers of the Bacon-Shor code on a grid of qubits.
+    ///
+    /// The gauge qubits are not fixed and are counted as logical qubits
+    /// such that a grid of `r` by `c` qubits encodes `(r - 1)(c - 1) + 1` qubits.
+    /// Use `subsystem_bacon_shor_code` to keep track of the gauge qubits.
+    /// The qubit at the given row and column has index `row * columns + column`.
+    /// The X stabilizers act on pairs of adjacent rows and
+    /// the Z stabilizers act on pairs of adjacent columns.
+    ///
+    /// Arguments
+    /// ---------
+    /// rows : int
+    ///     The number of rows. Must be at least 2.
+    /// columns : int
+    ///     The number of columns. Must be at least 2.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If there are less than 2 rows or columns.
+    #[pyfn(module)]
+    #[pyo3(name = "bacon_shor_code", text_signature = "(rows, columns)")]
+    pub fn py_bacon_shor_code(rows: usize, columns: usize) -> PyResult<PyCssCode> {
+        bacon_shor_code(rows, columns)
+    }
+
+    /// Returns the Bacon-Shor subsystem code on a grid of qubits.
+    ///
+    /// The qubit at the given row and column has index `row * columns + column`.
+    /// The X gauge generators act on vertically adjacent qubits and
+    /// the Z gauge generators act on horizontally adjacent qubits.
+    /// The code encodes a single logical qubit.
+    ///
+    /// Arguments
+    /// ---------
+    /// rows : int
+    ///     The number of rows. Must be at least 2.
+    /// columns : int
+    ///     The number of columns. Must be at least 2.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import subsystem_bacon_shor_code
+    ///     >>> code = subsystem_bacon_shor_code(3, 3)
+    ///     >>> code.num_stabs(), code.num_gauge_qubits(), code.num_logicals()
+    ///     (4, 4, 1)
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If there are less than 2 rows or columns.
+    #[pyfn(module)]
+    #[pyo3(name = "subsystem_bacon_shor_code", text_signature = "(rows, columns)")]
+    pub fn py_subsystem_bacon_shor_code(rows: usize, columns: usize) -> PyResult<PySubsystemCode> {
+        subsystem_bacon_shor_code(rows, columns)
+    }
+
+    /// Returns the [[2^m - 1, 1, 3]] quantum Reed-Muller code.
+    ///
+    /// The X stabilizers are the checks of the Hamming code of order `m`
+    /// and the Z stabilizers generate the dual of the punctured
+    /// Reed-Muller code RM(1, m). The code admits a transversal T gate.
+    /// The default order gives the [[15, 1, 3]] code.
+    ///
+    /// Arguments
+    /// ---------
+    /// order : int, default = 4
+    ///     The order `m` of the code. Must be between 3 and 10.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import quantum_reed_muller_code
+    ///     >>> code = quantum_reed_muller_code()
+    ///     >>> len(code), code.num_x_stabs(), code.num_z_stabs()
+    ///     (15, 4, 10)
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the order is not in the valid range.
+    #[pyfn(module, order = "4")]
+    #[pyo3(name = "quantum_reed_muller_code", text_signature = "(order=4)")]
+    pub fn py_quantum_reed_muller_code(order: usize) -> PyResult<PyCssCode> {
+        quantum_reed_muller_code(order)
+    }
+
+    /// Returns the CSS code defined from a triorthogonal matrix.
+    ///
+    /// A matrix is triorthogonal if every pair and every triple of rows
+    /// overlap on an even number of positions.
+    /// The X stabilizers are the even weight rows of the matrix
+    /// and the Z stabilizers generate the orthogonal complement of
+    /// the row space. Each odd weight row is an X logical operator.
+    ///
+    /// Arguments
+    /// ---------
+    /// matrix : BinaryMatrix
+    ///     A triorthogonal matrix.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the matrix is not triorthogonal.
+    #[pyfn(module)]
+    #[pyo3(name = "triorthogonal_code", text_signature = "(matrix)")]
+    pub fn py_triorthogonal_code(matrix: &PyBinaryMatrix) -> PyResult<PyCssCode> {
+        triorthogonal_code(&matrix.inner)
+    }
+
+    /// Returns the [[2^m, 2^m - 2 dim RM(m - r - 1, m), 2^(m - r)]]
+    /// quantum Reed-Muller code.
+    ///
+    /// Both the X and the Z stabilizers generate the Reed-Muller code
+    /// RM(m - r - 1, m) which is the dual of RM(r, m).
+    /// The code of degree 1 with 3 variables encodes no qubit,
+    /// the codes of degree `m - 2` are the [[2^m, 2^m - 2m - 2, 4]] codes
+    /// and the code of degree 1 with 2 variables is the [[4, 2, 2]] code.
+    ///
+    /// Arguments
+    /// ---------
+    /// degree : int
+    ///     The degree `r` of the Reed-Muller code RM(r, m).
+    ///     Must be between `m // 2` and `m - 1`.
+    /// num_variables : int
+    ///     The number of variables `m`. Must be at most 10.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import reed_muller_css_code
+    ///     >>> code = reed_muller_css_code(2, 4)
+    ///     >>> len(code), code.num_x_logicals()
+    ///     (16, 6)
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the degree or the number of variables is not in the valid range.
+    #[pyfn(module)]
+    #[pyo3(
+        name = "reed_muller_css_code",
+        text_signature = "(degree, num_variables)"
+    )]
+    pub fn py_reed_muller_css_code(degree: usize, num_variables: usize) -> PyResult<PyCssCode> {
+        reed_muller_css_code(degree, num_variables)
+    }
+
+    /// Returns the triorthogonal code of the punctured Reed-Muller code RM(r, m)*.
+    ///
+    /// The matrix given to `triorthogonal_code` has a row for each monomial
+    /// of degree at most `r` evaluated on the non-zero points of F_2^m.
+    /// It is triorthogonal if `3r < m`. The constant monomial is the
+    /// only odd weight row, so the code encodes a single qubit
+    /// and the degree 1 codes are the [[2^m - 1, 1, 3]] codes
+    /// of `quantum_reed_muller_code`.
+    ///
+    /// Arguments
+    /// ---------
+    /// degree : int
+    ///     The degree `r`. Must be positive and less than `m / 3`.
+    /// num_variables : int
+    ///     The number of variables `m`. Must be at most 10.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import reed_muller_triorthogonal_code
+    ///     >>> code = reed_muller_triorthogonal_code(2, 7)
+    ///     >>> len(code), code.num_x_stabs(), code.num_x_logicals()
+    ///     (127, 28, 1)
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the degree or the number of variables is not in the valid range.
+    #[pyfn(module)]
+    #[pyo3(
+        name = "reed_muller_triorthogonal_code",
+        text_signature = "(degree, num_variables)"
+    )]
+    pub fn py_reed_muller_triorthogonal_code(
+        degree: usize,
+        num_variables: usize,
+    ) -> PyResult<PyCssCode> {
+        reed_muller_triorthogonal_code(degree, num_variables)
+    }
+
+    /// Returns the planar surface code of the given distance.
+    ///
+    /// The code is defined on a grid of `2d - 1` rows and columns.
+    /// The sites with an even sum of row and column are qubits,
+    /// the sites with an even row and an odd column are X stabilizers and
+    /// the sites with an odd row and an even column are Z stabilizers.
+    /// Each stabilizer acts on the qubits of the neighboring sites
+    /// and each type of site is indexed in row major order.
+    /// The X logical acts on the first column and the Z logical on the first row.
+    ///
+    /// Arguments
+    /// ---------
+    /// distance : int
+    ///     The distance `d` of the code. Must be at least 2.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import planar_surface_code
+    ///     >>> code = planar_surface_code(3)
+    ///     >>> len(code), code.num_x_stabs(), code.num_z_stabs()
+    ///     (13, 6, 6)
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the distance is less than 2.
+    #[pyfn(module)]
+    #[pyo3(name = "planar_surface_code", text_signature = "(distance)")]
+    pub fn py_planar_surface_code(distance: usize) -> PyResult<PyCssCode> {
+        planar_surface_code(distance)
+    }
+
     /// Returns the hypergraph product of two linear codes.
     ///
     /// Arguments
@@ -1,14 +1,20 @@
+use crate::packed::{syndromes_of, PackedMatrix};
 use crate::randomness::PyRng;
-use crate::sparse::{PyBinaryMatrix, PyBinaryVector};
+use crate::sparse::{binary_matrix_from, PyBinaryMatrix, PyBinarySubspace, PyBinaryVector};
+use crate::tanner_graph::TannerGraph;
 use ldpc::classical::LinearCode;
-use pyo3::exceptions::PyValueError;
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use pyo3::PyObjectProtocol;
 use pyo3::PySequenceProtocol;
 use pyo3::ToPyObject;
 use rand::distributions::{Bernoulli, Distribution};
-use sparse_bin_mat::SparseBinVec;
+use rand::seq::SliceRandom;
+use sparse_bin_mat::{SparseBinMat, SparseBinVec};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 pub(crate) fn random_regular_code(
     num_bits: usize,
@@ -23,20 +29,289 @@ pub(crate) fn random_regular_code(
         .bit_degree(bit_degree)
         .check_degree(check_degree)
         .sample_with(&mut rng.inner)
-        .map(|code| PyLinearCode { inner: code })
+        .map(PyLinearCode::from)
         .map_err(|error| PyValueError::new_err(error.to_string()))
 }
 
-pub(crate) fn hamming_code() -> PyLinearCode {
-    PyLinearCode {
-        inner: LinearCode::hamming_code(),
+pub(crate) fn progressive_edge_growth_code(
+    num_checks: usize,
+    bit_degrees: Vec<usize>,
+    rng: &mut PyRng,
+) -> PyResult<PyLinearCode> {
+    if let Some(degree) = bit_degrees.iter().find(|degree| **degree > num_checks) {
+        return Err(PyValueError::new_err(format!(
+            "bit degree {} is larger than the number of checks {}",
+            degree, num_checks
+        )));
+    }
+    let mut graph = TannerGraph::without_edges(bit_degrees.len(), num_checks);
+    let mut bits: Vec<usize> = (0..bit_degrees.len()).collect();
+    bits.sort_by_key(|bit| bit_degrees[*bit]);
+    for bit in bits {
+        for _ in 0..bit_degrees[bit] {
+            let candidates = farthest_checks_from_bit(&graph, bit);
+            let check = least_connected_check(&graph, &candidates, rng);
+            graph.add_edge(bit, check);
+        }
+    }
+    Ok(LinearCode::from_parity_check_matrix(graph.to_parity_check_matrix()).into())
+}
+
+// Returns the checks that are not reachable from the bit if any
+// or else the checks that are the farthest from the bit.
+fn farthest_checks_from_bit(graph: &TannerGraph, bit: usize) -> Vec<usize> {
+    let distances = graph.check_distances_from_bit(bit);
+    let unreachable: Vec<usize> = (0..graph.num_checks())
+        .filter(|check| distances[*check].is_none())
+        .collect();
+    if !unreachable.is_empty() {
+        return unreachable;
+    }
+    let max_distance = distances.iter().flatten().max().cloned().unwrap_or(0);
+    (0..graph.num_checks())
+        .filter(|check| distances[*check] == Some(max_distance) && !graph.has_edge(bit, *check))
+        .collect()
+}
+
+// Returns one of the candidates with the smallest degree chosen uniformly at random.
+fn least_connected_check(graph: &TannerGraph, candidates: &[usize], rng: &mut PyRng) -> usize {
+    let min_degree = candidates
+        .iter()
+        .map(|check| graph.check_degree(*check))
+        .min()
+        .unwrap_or(0);
+    let least_connected: Vec<usize> = candidates
+        .iter()
+        .filter(|check| graph.check_degree(**check) == min_degree)
+        .cloned()
+        .collect();
+    *least_connected
+        .choose(&mut rng.inner)
+        .expect("[Bug] no candidate check")
+}
+
+pub(crate) fn irregular_code(
+    num_bits: usize,
+    bit_distribution: &[f64],
+    check_distribution: &[f64],
+    rng: &mut PyRng,
+    avoid_repeated_edges: bool,
+    avoid_four_cycles: bool,
+    max_attempts: usize,
+) -> PyResult<PyLinearCode> {
+    validate_degree_distribution(bit_distribution)?;
+    validate_degree_distribution(check_distribution)?;
+    let bit_degrees = bit_degrees_from_distribution(num_bits, bit_distribution);
+    let num_edges = bit_degrees.iter().sum();
+    let check_degrees = check_degrees_from_distribution(num_edges, check_distribution);
+    if num_edges > 0 && check_degrees.is_empty() {
+        return Err(PyValueError::new_err(
+            "the degree distributions don't lead to any check",
+        ));
+    }
+    let bit_sockets: Vec<usize> = bit_degrees
+        .iter()
+        .enumerate()
+        .flat_map(|(bit, degree)| std::iter::repeat_n(bit, *degree))
+        .collect();
+    let mut check_sockets: Vec<usize> = check_degrees
+        .iter()
+        .enumerate()
+        .flat_map(|(check, degree)| std::iter::repeat_n(check, *degree))
+        .collect();
+    for _ in 0..max_attempts {
+        check_sockets.shuffle(&mut rng.inner);
+        if let Some(graph) = match_sockets(
+            &bit_sockets,
+            &mut check_sockets,
+            bit_degrees.len(),
+            check_degrees.len(),
+            avoid_repeated_edges,
+            avoid_four_cycles,
+        ) {
+            return Ok(LinearCode::from_parity_check_matrix(graph.to_parity_check_matrix()).into());
+        }
+    }
+    Err(PyValueError::new_err(format!(
+        "failed to sample a code satisfying the constraints in {} attempts",
+        max_attempts
+    )))
+}
+
+fn validate_degree_distribution(distribution: &[f64]) -> PyResult<()> {
+    if distribution
+        .iter()
+        .any(|coefficient| !coefficient.is_finite())
+    {
+        return Err(PyValueError::new_err(
+            "degree distribution has non-finite coefficients",
+        ));
+    }
+    if distribution.iter().any(|coefficient| *coefficient < 0.0) {
+        return Err(PyValueError::new_err(
+            "degree distribution has negative coefficients",
+        ));
+    }
+    let sum: f64 = distribution.iter().sum();
+    if (sum - 1.0).abs() > 1e-6 {
+        return Err(PyValueError::new_err(format!(
+            "degree distribution coefficients sum to {} instead of 1",
+            sum
+        )));
+    }
+    Ok(())
+}
+
+// Converts an edge-perspective distribution into the degree of each bit.
+// The number of bits of degree d is proportional to λ_d / d.
+fn bit_degrees_from_distribution(num_bits: usize, distribution: &[f64]) -> Vec<usize> {
+    let node_fractions = node_perspective_fractions(distribution);
+    round_preserving_total(num_bits, &node_fractions)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, count)| std::iter::repeat_n(index + 1, count))
+        .collect()
+}
+
+// Converts an edge-perspective distribution into the degree of each check
+// such that the degrees sum to the number of edges.
+// The degrees of the checks are adjusted one by one if rounding
+// the number of checks of each degree doesn't give the right number of edges.
+fn check_degrees_from_distribution(num_edges: usize, distribution: &[f64]) -> Vec<usize> {
+    let edges_per_check: f64 = distribution
+        .iter()
+        .enumerate()
+        .map(|(index, coefficient)| coefficient / (index + 1) as f64)
+        .sum();
+    let num_checks = (num_edges as f64 * edges_per_check).round() as usize;
+    let node_fractions = node_perspective_fractions(distribution);
+    let mut degrees: Vec<usize> = round_preserving_total(num_checks, &node_fractions)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(index, count)| std::iter::repeat_n(index + 1, count))
+        .collect();
+    let mut total: usize = degrees.iter().sum();
+    while total != num_edges && !degrees.is_empty() {
+        if total < num_edges {
+            let smallest = (0..degrees.len()).min_by_key(|c| degrees[*c]).unwrap();
+            degrees[smallest] += 1;
+            total += 1;
+        } else {
+            let largest = (0..degrees.len()).max_by_key(|c| degrees[*c]).unwrap();
+            degrees[largest] -= 1;
+            total -= 1;
+        }
+    }
+    degrees
+}
+
+fn node_perspective_fractions(distribution: &[f64]) -> Vec<f64> {
+    let weights: Vec<f64> = distribution
+        .iter()
+        .enumerate()
+        .map(|(index, coefficient)| coefficient / (index + 1) as f64)
+        .collect();
+    let total: f64 = weights.iter().sum();
+    weights.into_iter().map(|weight| weight / total).collect()
+}
+
+// Splits total into integers proportional to the fractions
+// using the largest remainder method.
+fn round_preserving_total(total: usize, fractions: &[f64]) -> Vec<usize> {
+    let targets: Vec<f64> = fractions.iter().map(|f| f * total as f64).collect();
+    let mut counts: Vec<usize> = targets.iter().map(|t| t.floor() as usize).collect();
+    let mut by_remainder: Vec<usize> = (0..targets.len()).collect();
+    by_remainder.sort_by(|a, b| {
+        let remainder_a = targets[*a] - targets[*a].floor();
+        let remainder_b = targets[*b] - targets[*b].floor();
+        remainder_b.partial_cmp(&remainder_a).unwrap()
+    });
+    let missing = total - counts.iter().sum::<usize>();
+    for index in by_remainder.into_iter().take(missing) {
+        counts[index] += 1;
     }
+    counts
+}
+
+// Greedily connects each bit socket to one of the remaining check sockets
+// satisfying the constraints.
+// The check sockets are assumed to be shuffled.
+fn match_sockets(
+    bit_sockets: &[usize],
+    check_sockets: &mut [usize],
+    num_bits: usize,
+    num_checks: usize,
+    avoid_repeated_edges: bool,
+    avoid_four_cycles: bool,
+) -> Option<TannerGraph> {
+    let mut graph = TannerGraph::without_edges(num_bits, num_checks);
+    for (socket, bit) in bit_sockets.iter().enumerate() {
+        let chosen = (socket..check_sockets.len()).find(|candidate| {
+            let check = check_sockets[*candidate];
+            !(avoid_repeated_edges && graph.has_edge(*bit, check)
+                || avoid_four_cycles && graph.would_create_four_cycle(*bit, check))
+        })?;
+        check_sockets.swap(socket, chosen);
+        let check = check_sockets[socket];
+        if graph.has_edge(*bit, check) {
+            graph.remove_edge(*bit, check);
+        } else {
+            graph.add_edge(*bit, check);
+        }
+    }
+    Some(graph)
 }
 
 pub(crate) fn repetition_code(length: usize) -> PyLinearCode {
-    PyLinearCode {
-        inner: LinearCode::repetition_code(length),
+    LinearCode::repetition_code(length).into()
+}
+
+fn from_generator_matrix(generator_matrix: SparseBinMat) -> PyResult<LinearCode> {
+    check_generators_are_independent(&generator_matrix)?;
+    Ok(LinearCode::from_generator_matrix(generator_matrix))
+}
+
+fn from_matrices(
+    parity_check_matrix: SparseBinMat,
+    generator_matrix: SparseBinMat,
+) -> PyResult<PyLinearCode> {
+    if parity_check_matrix.number_of_columns() != generator_matrix.number_of_columns() {
+        return Err(PyValueError::new_err(format!(
+            "parity check matrix has {} columns, but generator matrix has {} columns",
+            parity_check_matrix.number_of_columns(),
+            generator_matrix.number_of_columns()
+        )));
     }
+    if !(&parity_check_matrix * &generator_matrix.transposed()).is_zero() {
+        return Err(PyValueError::new_err("matrices are not orthogonal"));
+    }
+    check_generators_are_independent(&generator_matrix)?;
+    let check_rank = PackedMatrix::from_sparse(&parity_check_matrix).rank();
+    let length = parity_check_matrix.number_of_columns();
+    if check_rank + generator_matrix.number_of_rows() != length {
+        return Err(PyValueError::new_err(format!(
+            "generator matrix has rank {} but the nullspace of the parity check matrix has dimension {}",
+            generator_matrix.number_of_rows(),
+            length - check_rank
+        )));
+    }
+    Ok(PyLinearCode {
+        inner: LinearCode::from_parity_check_matrix(parity_check_matrix),
+        generator_matrix: Some(generator_matrix),
+    })
+}
+
+fn check_generators_are_independent(generator_matrix: &SparseBinMat) -> PyResult<()> {
+    let rank = PackedMatrix::from_sparse(generator_matrix).rank();
+    if rank < generator_matrix.number_of_rows() {
+        return Err(PyValueError::new_err(format!(
+            "generator matrix has {} rows but rank {}, remove the {} redundant generators",
+            generator_matrix.number_of_rows(),
+            rank,
+            generator_matrix.number_of_rows() - rank
+        )));
+    }
+    Ok(())
 }
 
 /// An implementation of linear codes optimized for LDPC codes.
@@ -47,14 +322,18 @@ pub(crate) fn repetition_code(length: usize) -> PyLinearCode {
 ///
 /// Parameters
 /// ----------
-/// parity_check_matrix : Optional[pyqec.sparse.BinaryMatrix]
+/// par_mat : Optional[BinaryMatrix]
 ///     The parity check matrix of the code.
 ///     Most be orthogonal to the generator matrix.
 ///     If omited, one is computed from the generator matrix.
-/// generator_matrix : Optional[pyqec.sparse.BinaryMatrix]
+///     Redundant checks are allowed.
+/// gen_mat : Optional[BinaryMatrix]
 ///     The generator matrix of the code.
 ///     Most be orthogonal to the parity check matrix.
 ///     If omited, one is computed from the parity check matrix.
+///     The generators must be linearly independent.
+///     If both matrices are given, the generators must span
+///     the whole nullspace of the parity check matrix.
 ///
 /// Example
 /// -------
@@ -67,12 +346,12 @@ pub(crate) fn repetition_code(length: usize) -> PyLinearCode {
 /// You can build a linear code from a parity check matrix
 ///     
 ///     >>> matrix = BinaryMatrix(7, [[0, 1, 2, 4], [0, 1, 3, 5], [0, 2, 3, 6]])
-///     >>> code_pcm = LinearCode(parity_check_matrix=pcm)
+///     >>> code_pcm = LinearCode(par_mat=matrix)
 ///
 /// or from a generator matrix
 ///
 ///     >>> matrix = BinaryMatrix(7, [[0, 4, 5, 6], [1, 4, 5], [2, 4, 6], [3, 5, 6]])
-///     >>> code_gm = LinearCode(generator_matrix=matrix)
+///     >>> code_gm = LinearCode(gen_mat=matrix)
 ///
 /// Note
 /// ----
@@ -87,15 +366,29 @@ pub(crate) fn repetition_code(length: usize) -> PyLinearCode {
 ///     False
 ///     >>> code_pcm.has_same_codespace(code_gm)
 ///     True
+///
+/// Raises
+/// ------
+/// ValueError
+///     If the matrices have different numbers of columns or are not orthogonal,
+///     if the generators are linearly dependent
+///     or if the generators don't span the nullspace of the parity check matrix.
 #[pyclass(name = "LinearCode", module = "qecstruct")]
 #[pyo3(text_signature = "(par_mat=None, gen_mat=None)")]
 pub struct PyLinearCode {
     pub(crate) inner: LinearCode,
+    // The generator matrix given with the parity check matrix to the constructor.
+    // LinearCode has no constructor taking both matrices
+    // and always uses the nullspace of the parity check matrix.
+    generator_matrix: Option<SparseBinMat>,
 }
 
 impl From<LinearCode> for PyLinearCode {
     fn from(inner: LinearCode) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            generator_matrix: None,
+        }
     }
 }
 
@@ -105,27 +398,85 @@ impl PyLinearCode {
     #[args(par_mat = "None", gen_mat = "None")]
     pub fn new(par_mat: Option<PyBinaryMatrix>, gen_mat: Option<PyBinaryMatrix>) -> PyResult<Self> {
         match (par_mat, gen_mat) {
-            (Some(h), Some(g)) => h.dot_with_matrix(&g.transposed()).and_then(|product| {
-                if product.is_zero() {
-                    Ok(Self {
-                        inner: LinearCode::from_parity_check_matrix(h.inner),
-                    })
-                } else {
-                    Err(PyValueError::new_err("matrices are not orthogonal"))
-                }
-            }),
-            (Some(h), None) => Ok(Self {
-                inner: LinearCode::from_parity_check_matrix(h.inner),
-            }),
-            (None, Some(g)) => Ok(Self {
-                inner: LinearCode::from_parity_check_matrix(g.inner),
-            }),
-            (None, None) => Ok(Self {
-                inner: LinearCode::empty(),
-            }),
+            (Some(h), Some(g)) => from_matrices(h.inner, g.inner),
+            (Some(h), None) => Ok(LinearCode::from_parity_check_matrix(h.inner).into()),
+            (None, Some(g)) => from_generator_matrix(g.inner).map(Self::from),
+            (None, None) => Ok(LinearCode::empty().into()),
         }
     }
 
+    /// Builds a quasi-cyclic code from a base matrix of circulant shifts.
+    ///
+    /// Each entry of the base matrix is expanded into a square block
+    /// of size `lift_size`.
+    /// An entry `s >= 0` is replaced by the identity matrix cyclically
+    /// shifted by `s` positions to the right and an entry `-1`
+    /// is replaced by the zero matrix.
+    ///
+    /// Parameters
+    /// ----------
+    /// base_matrix : Seq[Seq[int]]
+    ///     The shifts of the circulant blocks.
+    ///     All rows must have the same length.
+    /// lift_size : int
+    ///     The size of the circulant blocks.
+    ///
+    /// Returns
+    /// -------
+    /// LinearCode
+    ///     A code with a `lift_size * len(base_matrix)` rows parity check matrix.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from pyqec.classical import LinearCode
+    ///     >>> code = LinearCode.quasi_cyclic([[0, 1, -1], [2, -1, 0]], 3)
+    ///     >>> code.par_mat()
+    ///     [0, 4]
+    ///     [1, 5]
+    ///     [2, 3]
+    ///     [2, 6]
+    ///     [0, 7]
+    ///     [1, 8]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the rows of the base matrix have different lengths
+    ///     or if a shift is not in the range `[-1, lift_size)`.
+    #[staticmethod]
+    #[pyo3(text_signature = "(base_matrix, lift_size)")]
+    pub fn quasi_cyclic(base_matrix: Vec<Vec<i64>>, lift_size: usize) -> PyResult<Self> {
+        let num_block_columns = base_matrix.first().map(|row| row.len()).unwrap_or(0);
+        let mut rows = Vec::with_capacity(base_matrix.len() * lift_size);
+        for block_row in base_matrix.iter() {
+            if block_row.len() != num_block_columns {
+                return Err(PyValueError::new_err(
+                    "all rows of the base matrix must have the same length",
+                ));
+            }
+            if let Some(shift) = block_row
+                .iter()
+                .find(|shift| **shift < -1 || **shift >= lift_size as i64)
+            {
+                return Err(PyValueError::new_err(format!(
+                    "shift {} is invalid for lift size {}",
+                    shift, lift_size
+                )));
+            }
+            for row in 0..lift_size {
+                let positions = block_row
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, shift)| **shift >= 0)
+                    .map(|(block, shift)| block * lift_size + (row + *shift as usize) % lift_size)
+                    .collect();
+                rows.push(positions);
+            }
+        }
+        let matrix = SparseBinMat::new(num_block_columns * lift_size, rows);
+        Ok(LinearCode::from_parity_check_matrix(matrix).into())
+    }
+
     /// The parity check matrix of the code.
     #[pyo3(text_signature = "(self)")]
     pub fn par_mat(&self) -> PyBinaryMatrix {
@@ -135,7 +486,66 @@ impl PyLinearCode {
     /// The parity check matrix of the code.
     #[pyo3(text_signature = "(self)")]
     pub fn gen_mat(&self) -> PyBinaryMatrix {
-        self.inner.generator_matrix().clone().into()
+        self.generator_matrix().clone().into()
+    }
+
+    /// The subspace of all codewords.
+    ///
+    /// This is the kernel of the parity check matrix.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import hamming_code
+    ///     >>> hamming_code().codespace().dimension()
+    ///     4
+    #[pyo3(text_signature = "(self)")]
+    pub fn codespace(&self) -> PyBinarySubspace {
+        PyBinarySubspace::orthogonal_to(self.inner.parity_check_matrix())
+    }
+
+    /// The subspace spanned by the parity checks.
+    ///
+    /// This is the codespace of the dual code.
+    #[pyo3(text_signature = "(self)")]
+    pub fn check_space(&self) -> PyBinarySubspace {
+        PyBinarySubspace::from_generators(self.inner.parity_check_matrix())
+    }
+
+    /// The reduced row echelon form of the parity check matrix without zero rows.
+    ///
+    /// Two codes have the same canonical parity check matrix
+    /// if and only if they have the same codespace.
+    #[pyo3(text_signature = "(self)")]
+    pub fn canonical_par_mat(&self) -> PyBinaryMatrix {
+        self.check_space().basis()
+    }
+
+    /// The reduced row echelon form of the generator matrix without zero rows.
+    ///
+    /// Two codes have the same canonical generator matrix
+    /// if and only if they have the same codespace.
+    #[pyo3(text_signature = "(self)")]
+    pub fn canonical_gen_mat(&self) -> PyBinaryMatrix {
+        self.codespace().basis()
+    }
+
+    /// Returns the code defined by the canonical parity check matrix.
+    ///
+    /// Codes with the same codespace have equal canonical forms.
+    /// Thus, canonical forms can be used to deduplicate codes in sets or dicts.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix, LinearCode
+    ///     >>> code = LinearCode(BinaryMatrix(3, [[0, 1], [1, 2]]))
+    ///     >>> other = LinearCode(BinaryMatrix(3, [[0, 2], [0, 1], [1, 2]]))
+    ///     >>> code == other
+    ///     False
+    ///     >>> code.canonical_form() == other.canonical_form()
+    ///     True
+    #[pyo3(text_signature = "(self)")]
+    pub fn canonical_form(&self) -> Self {
+        LinearCode::from_parity_check_matrix(self.canonical_par_mat().inner).into()
     }
 
     /// The number of bits in the code.
@@ -209,6 +619,39 @@ impl PyLinearCode {
         Ok(self.inner.syndrome_of(&message.inner).into())
     }
 
+    /// Computes the syndromes of many messages at once.
+    ///
+    /// Parameters
+    /// ----------
+    /// messages: BinaryMatrix or numpy.ndarray
+    ///     A matrix where each row is a message.
+    ///     A 2D array must only contain 0s and 1s.
+    ///
+    /// Returns
+    /// -------
+    /// BinaryMatrix
+    ///     A matrix where each row is the syndrome of
+    ///     the corresponding message.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix, repetition_code
+    ///     >>> code = repetition_code(3)
+    ///     >>> code.syndromes_of(BinaryMatrix(3, [[0], [1], [0, 1, 2]]))
+    ///     [0]
+    ///     [0, 1]
+    ///     []
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the number of columns is not the length of the code.
+    #[pyo3(text_signature = "(self, messages)")]
+    pub fn syndromes_of(&self, messages: &PyAny) -> PyResult<PyBinaryMatrix> {
+        let messages = binary_matrix_from(messages)?;
+        syndromes_of(&messages, self.inner.parity_check_matrix()).map(PyBinaryMatrix::from)
+    }
+
     /// Checks if the given message is a codeword of the code.
     ///
     /// Parameters
@@ -226,6 +669,108 @@ impl PyLinearCode {
         self.inner.has_codeword(&message.inner)
     }
 
+    /// The positions of the information bits in systematic encoding.
+    ///
+    /// These are the pivots of the canonical generator matrix.
+    /// Each codeword is uniquely determined by its values on these positions.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import repetition_code
+    ///     >>> repetition_code(3).information_set()
+    ///     [0]
+    #[pyo3(text_signature = "(self)")]
+    pub fn information_set(&self) -> Vec<usize> {
+        self.codespace().pivots()
+    }
+
+    /// Encodes a message using the canonical generator matrix.
+    ///
+    /// The encoding is systematic, that is the value of the codeword
+    /// at the i-th position of the information set is
+    /// the i-th bit of the message.
+    ///
+    /// Parameters
+    /// ----------
+    /// message: BinaryVector
+    ///     A message of length equal to the dimension of the code.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryVector, repetition_code
+    ///     >>> repetition_code(3).encode(BinaryVector(1, [0]))
+    ///     [0, 1, 2]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the message length is not the dimension of the code.
+    #[pyo3(text_signature = "(self, message)")]
+    pub fn encode(&self, message: &PyBinaryVector) -> PyResult<PyBinaryVector> {
+        let messages =
+            SparseBinMat::new(message.inner.len(), vec![message.inner.as_slice().to_vec()]);
+        self.encode_rows(&messages)
+            .map(|codewords| codewords.row(0).unwrap().to_owned().into())
+    }
+
+    /// Encodes each row of a matrix of messages.
+    ///
+    /// Parameters
+    /// ----------
+    /// messages: BinaryMatrix or numpy.ndarray
+    ///     A matrix where each row is a message.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the number of columns is not the dimension of the code.
+    #[pyo3(text_signature = "(self, messages)")]
+    pub fn encode_many(&self, messages: &PyAny) -> PyResult<PyBinaryMatrix> {
+        self.encode_rows(&binary_matrix_from(messages)?)
+            .map(PyBinaryMatrix::from)
+    }
+
+    /// Recovers the message encoded in a codeword.
+    ///
+    /// This is the inverse of `encode`.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryVector, repetition_code
+    ///     >>> repetition_code(3).unencode(BinaryVector(3, [0, 1, 2]))
+    ///     [0]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the vector is not a codeword.
+    #[pyo3(text_signature = "(self, codeword)")]
+    pub fn unencode(&self, codeword: &PyBinaryVector) -> PyResult<PyBinaryVector> {
+        let codewords = SparseBinMat::new(
+            codeword.inner.len(),
+            vec![codeword.inner.as_slice().to_vec()],
+        );
+        self.unencode_rows(&codewords)
+            .map(|messages| messages.row(0).unwrap().to_owned().into())
+    }
+
+    /// Recovers the message encoded in each row of a matrix of codewords.
+    ///
+    /// Parameters
+    /// ----------
+    /// codewords: BinaryMatrix or numpy.ndarray
+    ///     A matrix where each row is a codeword.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If a row is not a codeword.
+    #[pyo3(text_signature = "(self, codewords)")]
+    pub fn unencode_many(&self, codewords: &PyAny) -> PyResult<PyBinaryMatrix> {
+        self.unencode_rows(&binary_matrix_from(codewords)?)
+            .map(PyBinaryMatrix::from)
+    }
+
     /// Generates a random codeword from the given RNG.
     ///
     /// Parameters
@@ -240,8 +785,7 @@ impl PyLinearCode {
     #[pyo3(text_signature = "(self, rng)")]
     pub fn random_codeword(&self, rng: &mut PyRng) -> PyBinaryVector {
         let distribution = Bernoulli::new(0.5).unwrap();
-        self.inner
-            .generator_matrix()
+        self.generator_matrix()
             .rows()
             .filter(|_| distribution.sample(&mut rng.inner))
             .fold(SparseBinVec::zeros(self.inner.len()), |codeword, row| {
@@ -265,14 +809,121 @@ impl PyLinearCode {
     ///     as this code codewords.
     #[pyo3(text_signature = "(self, other)")]
     pub fn has_same_codespace(&self, other: &Self) -> bool {
-        self.inner.has_same_codespace(&other.inner)
+        self.codespace() == other.codespace()
+    }
+
+    /// Returns the length of the shortest cycle of the Tanner graph
+    /// or -1 if the graph has no cycle.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import hamming_code
+    ///     >>> code = hamming_code()
+    ///     >>> code.girth()
+    ///     4
+    #[pyo3(text_signature = "(self)")]
+    pub fn girth(&self) -> i64 {
+        self.tanner_graph()
+            .girth()
+            .map(|girth| girth as i64)
+            .unwrap_or(-1)
+    }
+
+    /// Returns the number of cycles of the given length
+    /// in the Tanner graph.
+    ///
+    /// Caution
+    /// -------
+    /// The execution time scales exponentially with the length.
+    /// This is meant to count short cycles of length 4, 6 or 8.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the length is odd or smaller than 4.
+    #[pyo3(text_signature = "(self, length)")]
+    pub fn num_cycles(&self, length: usize) -> PyResult<usize> {
+        if length < 4 || length % 2 == 1 {
+            return Err(PyValueError::new_err(format!(
+                "cycles of a bipartite graph have even length of at least 4, not {}",
+                length
+            )));
+        }
+        Ok(self.tanner_graph().num_cycles(length))
+    }
+
+    /// Returns the stopping sets of at most the given size.
+    ///
+    /// A stopping set is a set of bits such that
+    /// no check is connected to exactly one of them.
+    /// Only the stopping sets inducing a connected subgraph
+    /// of the Tanner graph are returned
+    /// since any other stopping set is a disjoint union of those.
+    ///
+    /// Returns
+    /// -------
+    /// list[list[int]]
+    ///     The bits of each stopping set
+    ///     sorted by size and then lexicographically.
+    ///
+    /// Caution
+    /// -------
+    /// The execution time scales exponentially with the size.
+    #[pyo3(text_signature = "(self, max_size)")]
+    pub fn stopping_sets(&self, max_size: usize) -> Vec<Vec<usize>> {
+        self.tanner_graph().stopping_sets(max_size)
+    }
+
+    /// Returns the (a, b) trapping sets with `a <= max_size` and `b <= max_unsatisfied`.
+    ///
+    /// A (a, b) trapping set is a set of `a` bits inducing
+    /// a connected subgraph of the Tanner graph where `b` checks
+    /// are connected to an odd number of them.
+    /// These are the checks that are unsatisfied if the bits are flipped.
+    ///
+    /// Returns
+    /// -------
+    /// list[tuple[list[int], int]]
+    ///     The bits of each trapping set together with the number
+    ///     of unsatisfied checks sorted by size and then lexicographically.
+    ///
+    /// Caution
+    /// -------
+    /// The execution time scales exponentially with the size.
+    #[pyo3(text_signature = "(self, max_size, max_unsatisfied)")]
+    pub fn trapping_sets(
+        &self,
+        max_size: usize,
+        max_unsatisfied: usize,
+    ) -> Vec<(Vec<usize>, usize)> {
+        self.tanner_graph().trapping_sets(max_size, max_unsatisfied)
+    }
+
+    /// Returns the number of bits of each degree.
+    ///
+    /// The element at index `d` is the number of bits connected to `d` checks.
+    #[pyo3(text_signature = "(self)")]
+    pub fn bit_degree_distribution(&self) -> Vec<usize> {
+        self.tanner_graph().bit_degree_distribution()
+    }
+
+    /// Returns the number of checks of each degree.
+    ///
+    /// The element at index `d` is the number of checks connected to `d` bits.
+    #[pyo3(text_signature = "(self)")]
+    pub fn check_degree_distribution(&self) -> Vec<usize> {
+        self.tanner_graph().check_degree_distribution()
     }
 
     pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
         match state.extract::<&PyBytes>(py) {
             Ok(s) => serde_pickle::from_slice(s.as_bytes())
-                .map(|inner| {
+                // Codes pickled before the generator matrix was stored
+                // only hold the inner code.
+                .or_else(|_| serde_pickle::from_slice(s.as_bytes()).map(|inner| (inner, None)))
+                .map(|(inner, generator_matrix)| {
                     self.inner = inner;
+                    self.generator_matrix = generator_matrix;
                 })
                 .map_err(|error| PyValueError::new_err(error.to_string())),
             Err(e) => Err(e),
@@ -280,7 +931,51 @@ impl PyLinearCode {
     }
 
     pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
-        Ok(PyBytes::new(py, &serde_pickle::to_vec(&(&self.inner), true).unwrap()).to_object(py))
+        let state = (&self.inner, &self.generator_matrix);
+        Ok(PyBytes::new(py, &serde_pickle::to_vec(&state, true).unwrap()).to_object(py))
+    }
+}
+
+impl PyLinearCode {
+    pub(crate) fn generator_matrix(&self) -> &SparseBinMat {
+        self.generator_matrix
+            .as_ref()
+            .unwrap_or_else(|| self.inner.generator_matrix())
+    }
+
+    fn is_equal_to(&self, other: &Self) -> bool {
+        self.inner == other.inner && self.generator_matrix() == other.generator_matrix()
+    }
+
+    fn tanner_graph(&self) -> TannerGraph {
+        TannerGraph::from_parity_check_matrix(self.inner.parity_check_matrix())
+    }
+
+    fn encode_rows(&self, messages: &SparseBinMat) -> PyResult<SparseBinMat> {
+        let codespace = self.codespace();
+        if messages.number_of_columns() != codespace.dimension() {
+            return Err(PyValueError::new_err(format!(
+                "messages of length {} can't be encoded by a code of dimension {}",
+                messages.number_of_columns(),
+                codespace.dimension()
+            )));
+        }
+        Ok(PackedMatrix::from_sparse(codespace.basis_matrix())
+            .sparse_rows_combinations(messages)
+            .to_sparse())
+    }
+
+    fn unencode_rows(&self, codewords: &SparseBinMat) -> PyResult<SparseBinMat> {
+        let syndromes = syndromes_of(codewords, self.inner.parity_check_matrix())?;
+        if let Some(row) = syndromes.rows().position(|syndrome| !syndrome.is_zero()) {
+            return Err(PyValueError::new_err(format!(
+                "row {} is not a codeword",
+                row
+            )));
+        }
+        codewords
+            .keep_only_columns(&self.information_set())
+            .map_err(|error| PyValueError::new_err(error.to_string()))
     }
 }
 
@@ -290,9 +985,24 @@ impl PyObjectProtocol for PyLinearCode {
         format!(
             "Parity check matrix:\n{}\nGenerator matrix:\n{}",
             self.inner.parity_check_matrix(),
-            self.inner.generator_matrix(),
+            self.generator_matrix(),
         )
     }
+
+    fn __richcmp__(&self, other: PyRef<Self>, op: CompareOp) -> PyResult<bool> {
+        match op {
+            CompareOp::Eq => Ok(self.is_equal_to(&other)),
+            CompareOp::Ne => Ok(!self.is_equal_to(&other)),
+            _ => Err(PyNotImplementedError::new_err("not implemented")),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.inner.hash(&mut hasher);
+        self.generator_matrix().hash(&mut hasher);
+        hasher.finish()
+    }
 }
 
 #[pyproto]
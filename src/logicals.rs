@@ -0,0 +1,146 @@
+use crate::sparse::PyBinarySubspace;
+use sparse_bin_mat::{SparseBinMat, SparseBinVec};
+
+/// The X and Z logical operators of a CSS code paired such that
+/// the i-th X logical anticommutes only with the i-th Z logical.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PairedLogicals {
+    x_logicals: Vec<SparseBinVec>,
+    z_logicals: Vec<SparseBinVec>,
+}
+
+impl PairedLogicals {
+    /// Computes the paired logicals of the CSS code with the given stabilizers
+    /// using a symplectic Gram-Schmidt process.
+    ///
+    /// The candidates are representatives of the quotients of the normalizers
+    /// by the stabilizers, so they don't depend on the logicals stored in the code.
+    pub(crate) fn new(x_stabilizers: &SparseBinMat, z_stabilizers: &SparseBinMat) -> Self {
+        let x_candidates = quotient_representatives(z_stabilizers, x_stabilizers);
+        let mut z_candidates = quotient_representatives(x_stabilizers, z_stabilizers);
+        let mut x_logicals = Vec::with_capacity(x_candidates.len());
+        let mut z_logicals = Vec::with_capacity(z_candidates.len());
+        for mut x_logical in x_candidates {
+            for (x_paired, z_paired) in x_logicals.iter().zip(z_logicals.iter()) {
+                if anticommute(&x_logical, z_paired) {
+                    x_logical = &x_logical + x_paired;
+                }
+            }
+            let partner = match z_candidates
+                .iter()
+                .position(|z_logical| anticommute(&x_logical, z_logical))
+            {
+                Some(partner) => z_candidates.remove(partner),
+                // This can't happen since the normalizers are orthogonal
+                // complements of the stabilizers.
+                None => continue,
+            };
+            for z_logical in z_candidates.iter_mut() {
+                if anticommute(&x_logical, z_logical) {
+                    *z_logical = &*z_logical + &partner;
+                }
+            }
+            x_logicals.push(x_logical);
+            z_logicals.push(partner);
+        }
+        Self {
+            x_logicals,
+            z_logicals,
+        }
+    }
+
+    /// Reduces the weight of each logical by greedily adding stabilizers.
+    ///
+    /// Adding stabilizers doesn't change the pairing since
+    /// the stabilizers commute with all logicals.
+    pub(crate) fn minimize_weights(
+        &mut self,
+        x_stabilizers: &SparseBinMat,
+        z_stabilizers: &SparseBinMat,
+    ) {
+        for x_logical in self.x_logicals.iter_mut() {
+            *x_logical = reduce_weight(x_logical, x_stabilizers);
+        }
+        for z_logical in self.z_logicals.iter_mut() {
+            *z_logical = reduce_weight(z_logical, z_stabilizers);
+        }
+    }
+
+    /// Returns the bits of the X and Z logicals implemented by an operator
+    /// given by its X and Z parts.
+    ///
+    /// The i-th X bit is 1 if the X part anticommutes with the i-th Z logical
+    /// and the i-th Z bit is 1 if the Z part anticommutes with the i-th X logical.
+    pub(crate) fn action_of(
+        &self,
+        x_part: &SparseBinVec,
+        z_part: &SparseBinVec,
+    ) -> (SparseBinVec, SparseBinVec) {
+        (
+            commutation_bits(x_part, &self.z_logicals),
+            commutation_bits(z_part, &self.x_logicals),
+        )
+    }
+
+    /// Returns the X and Z logicals as the rows of two matrices.
+    pub(crate) fn to_matrices(&self, length: usize) -> (SparseBinMat, SparseBinMat) {
+        (
+            to_matrix(&self.x_logicals, length),
+            to_matrix(&self.z_logicals, length),
+        )
+    }
+}
+
+/// Checks if two operators of opposite types anticommute.
+pub(crate) fn anticommute(first: &SparseBinVec, second: &SparseBinVec) -> bool {
+    first.dot_with(second).unwrap() == 1.into()
+}
+
+fn commutation_bits(operator: &SparseBinVec, logicals: &[SparseBinVec]) -> SparseBinVec {
+    let positions = logicals
+        .iter()
+        .enumerate()
+        .filter(|(_, logical)| anticommute(operator, logical))
+        .map(|(position, _)| position)
+        .collect();
+    SparseBinVec::new(logicals.len(), positions)
+}
+
+// Returns representatives of the quotient of the kernel of the checks
+// by the row space of the stabilizers.
+fn quotient_representatives(
+    checks: &SparseBinMat,
+    stabilizers: &SparseBinMat,
+) -> Vec<SparseBinVec> {
+    PyBinarySubspace::orthogonal_to(checks)
+        .quotient_basis(&PyBinarySubspace::from_generators(stabilizers))
+        .unwrap()
+        .inner
+        .rows()
+        .map(|row| row.to_owned())
+        .collect()
+}
+
+fn reduce_weight(operator: &SparseBinVec, stabilizers: &SparseBinMat) -> SparseBinVec {
+    let mut operator = operator.clone();
+    let mut improved = true;
+    while improved {
+        improved = false;
+        for stabilizer in stabilizers.rows() {
+            let candidate = operator.bitwise_xor_with(&stabilizer).unwrap();
+            if candidate.weight() < operator.weight() {
+                operator = candidate;
+                improved = true;
+            }
+        }
+    }
+    operator
+}
+
+fn to_matrix(operators: &[SparseBinVec], length: usize) -> SparseBinMat {
+    let rows = operators
+        .iter()
+        .map(|operator| operator.as_slice().to_vec())
+        .collect();
+    SparseBinMat::new(length, rows)
+}
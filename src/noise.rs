@@ -3,11 +3,13 @@ use crate::randomness::PyRng;
 use crate::sparse::PyBinaryVector;
 use bincode::{deserialize, serialize};
 use ldpc::noise_model::{BinarySymmetricChannel, DepolarizingNoise, NoiseModel, Probability};
+use pauli::{PauliOperator, X, Y, Z};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::PyBytes;
 use pyo3::PyObjectProtocol;
 use pyo3::ToPyObject;
+use rand::Rng;
 
 /// An implementation of a binary symmetric channel.
 ///
@@ -42,7 +44,7 @@ impl PyBinarySymmetricChannel {
     }
 
     #[pyo3(text_signature = "(self)")]
-    fn error_probability(&self) -> f64 {
+    pub(crate) fn error_probability(&self) -> f64 {
         self.probability
     }
 
@@ -106,7 +108,7 @@ impl PyDepolarizingNoise {
     }
 
     #[pyo3(text_signature = "(self)")]
-    fn error_probability(&self) -> f64 {
+    pub(crate) fn error_probability(&self) -> f64 {
         self.probability
     }
 
@@ -136,3 +138,115 @@ impl PyObjectProtocol for PyDepolarizingNoise {
         format!("Depolarizing({})", self.error_probability())
     }
 }
+
+/// An implementation of a Pauli noise channel biased toward Z errors.
+///
+/// Each qubit is affected by one of {X, Y, Z} with total probability p
+/// where the bias is the ratio between the probabilities of Z errors
+/// and of X or Y errors, that is p_z / (p_x + p_y) with p_x = p_y.
+/// A bias of 0.5 gives depolarizing noise and an infinite bias
+/// gives pure dephasing noise.
+///
+/// Parameters
+/// ----------
+/// probability : float, default = 0.75
+///     The probability of a non identity error on each qubit.
+/// bias : float, default = 0.5
+///     The bias toward Z errors.
+///
+/// Raises
+/// ------
+/// ValueError
+///     If the probability is not between 0 and 1 or if the bias is negative.
+#[pyclass(name = "BiasedNoise", module = "qecstruct")]
+#[pyo3(text_signature = "(probability=0.75, bias=0.5)")]
+pub struct PyBiasedNoise {
+    probability: f64,
+    bias: f64,
+}
+
+impl PyBiasedNoise {
+    /// Returns the probabilities of X, Y and Z errors.
+    pub(crate) fn pauli_probabilities(&self) -> (f64, f64, f64) {
+        if self.bias.is_infinite() {
+            return (0.0, 0.0, self.probability);
+        }
+        let z_probability = self.probability * self.bias / (1.0 + self.bias);
+        let x_probability = (self.probability - z_probability) / 2.0;
+        (x_probability, x_probability, z_probability)
+    }
+}
+
+#[pymethods]
+impl PyBiasedNoise {
+    #[new]
+    #[args(probability = "0.75", bias = "0.5")]
+    pub fn new(probability: f64, bias: f64) -> PyResult<PyBiasedNoise> {
+        if Probability::try_new(probability).is_none() {
+            return Err(PyValueError::new_err(format!(
+                "{} is not a valid probability",
+                probability
+            )));
+        }
+        if bias.is_nan() || bias < 0.0 {
+            return Err(PyValueError::new_err(format!(
+                "{} is not a valid bias",
+                bias
+            )));
+        }
+        Ok(PyBiasedNoise { probability, bias })
+    }
+
+    #[pyo3(text_signature = "(self, length, rng)")]
+    fn sample(&self, length: usize, rng: &mut PyRng) -> PyPauliOperator {
+        let (x_probability, y_probability, _) = self.pauli_probabilities();
+        let (positions, paulis) = (0..length)
+            .filter_map(|position| {
+                let sample: f64 = rng.inner.gen();
+                if sample >= self.probability {
+                    None
+                } else if sample < x_probability {
+                    Some((position, X))
+                } else if sample < x_probability + y_probability {
+                    Some((position, Y))
+                } else {
+                    Some((position, Z))
+                }
+            })
+            .unzip();
+        PauliOperator::new(length, positions, paulis).into()
+    }
+
+    #[pyo3(text_signature = "(self)")]
+    pub(crate) fn error_probability(&self) -> f64 {
+        self.probability
+    }
+
+    #[pyo3(text_signature = "(self)")]
+    fn bias(&self) -> f64 {
+        self.bias
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                let (probability, bias) = deserialize(s.as_bytes()).unwrap();
+                self.probability = probability;
+                self.bias = bias;
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &serialize(&(&self.probability, &self.bias)).unwrap()).to_object(py))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PyBiasedNoise {
+    fn __repr__(&self) -> String {
+        format!("Biased({}, {})", self.error_probability(), self.bias())
+    }
+}
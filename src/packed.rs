@@ -0,0 +1,436 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use sparse_bin_mat::SparseBinMat;
+
+const WORD_SIZE: usize = 64;
+
+// The number of pivots eliminated at once with a table of all their combinations.
+const M4RI_BLOCK_SIZE: usize = 8;
+
+/// A dense binary matrix where each row is packed into 64-bit words.
+///
+/// Bit `c % 64` of the word `c / 64` of a row is the element in column `c`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub(crate) struct PackedMatrix {
+    num_rows: usize,
+    num_columns: usize,
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl PackedMatrix {
+    pub(crate) fn zeros(num_rows: usize, num_columns: usize) -> Self {
+        let words_per_row = num_columns.div_ceil(WORD_SIZE);
+        Self {
+            num_rows,
+            num_columns,
+            words_per_row,
+            words: vec![0; num_rows * words_per_row],
+        }
+    }
+
+    pub(crate) fn from_sparse(matrix: &SparseBinMat) -> Self {
+        let mut packed = Self::zeros(matrix.number_of_rows(), matrix.number_of_columns());
+        for (row, column) in matrix.non_trivial_elements() {
+            packed.flip(row, column);
+        }
+        packed
+    }
+
+    pub(crate) fn to_sparse(&self) -> SparseBinMat {
+        let rows = (0..self.num_rows)
+            .map(|row| self.row_positions(row))
+            .collect();
+        SparseBinMat::new(self.num_columns, rows)
+    }
+
+    pub(crate) fn identity(length: usize) -> Self {
+        let mut identity = Self::zeros(length, length);
+        for position in 0..length {
+            identity.flip(position, position);
+        }
+        identity
+    }
+
+    // Checks that the number of words matches the shape,
+    // which may not hold for a deserialized matrix.
+    pub(crate) fn has_consistent_shape(&self) -> bool {
+        self.words_per_row == self.num_columns.div_ceil(WORD_SIZE)
+            && self.num_rows.checked_mul(self.words_per_row) == Some(self.words.len())
+    }
+
+    pub(crate) fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub(crate) fn num_columns(&self) -> usize {
+        self.num_columns
+    }
+
+    pub(crate) fn get(&self, row: usize, column: usize) -> bool {
+        (self.row(row)[column / WORD_SIZE] >> (column % WORD_SIZE)) & 1 == 1
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.words.iter().all(|word| *word == 0)
+    }
+
+    pub(crate) fn flip(&mut self, row: usize, column: usize) {
+        self.row_mut(row)[column / WORD_SIZE] ^= 1 << (column % WORD_SIZE);
+    }
+
+    pub(crate) fn row(&self, row: usize) -> &[u64] {
+        &self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    pub(crate) fn row_mut(&mut self, row: usize) -> &mut [u64] {
+        &mut self.words[row * self.words_per_row..(row + 1) * self.words_per_row]
+    }
+
+    /// Returns the positions of the ones in the given row.
+    pub(crate) fn row_positions(&self, row: usize) -> Vec<usize> {
+        let mut positions = Vec::new();
+        for (index, word) in self.row(row).iter().enumerate() {
+            let mut word = *word;
+            while word != 0 {
+                positions.push(index * WORD_SIZE + word.trailing_zeros() as usize);
+                word &= word - 1;
+            }
+        }
+        positions
+    }
+
+    /// Returns the matrix with the columns of other appended to the right.
+    ///
+    /// This assumes that both matrices have the same number of rows.
+    pub(crate) fn horizontal_concat_with(&self, other: &Self) -> Self {
+        let mut concatenated = Self::zeros(self.num_rows, self.num_columns + other.num_columns);
+        for row in 0..self.num_rows {
+            concatenated.row_mut(row)[..self.words_per_row].copy_from_slice(self.row(row));
+            for column in other.row_positions(row) {
+                concatenated.flip(row, self.num_columns + column);
+            }
+        }
+        concatenated
+    }
+
+    /// Returns the matrix restricted to the columns in the given range.
+    pub(crate) fn columns_in(&self, start: usize, end: usize) -> Self {
+        let mut restricted = Self::zeros(self.num_rows, end - start);
+        for row in 0..self.num_rows {
+            for column in self.row_positions(row) {
+                if (start..end).contains(&column) {
+                    restricted.flip(row, column - start);
+                }
+            }
+        }
+        restricted
+    }
+
+    /// Returns the matrix restricted to the first rows.
+    pub(crate) fn first_rows(&self, num_rows: usize) -> Self {
+        Self {
+            num_rows,
+            num_columns: self.num_columns,
+            words_per_row: self.words_per_row,
+            words: self.words[..num_rows * self.words_per_row].to_vec(),
+        }
+    }
+
+    pub(crate) fn transposed(&self) -> Self {
+        let mut transposed = Self::zeros(self.num_columns, self.num_rows);
+        for row in 0..self.num_rows {
+            for column in self.row_positions(row) {
+                transposed.flip(column, row);
+            }
+        }
+        transposed
+    }
+
+    /// Returns the product `self * other`.
+    ///
+    /// This assumes that the number of columns of self
+    /// is the number of rows of other.
+    pub(crate) fn dot_with(&self, other: &Self) -> Self {
+        let mut product = Self::zeros(self.num_rows, other.num_columns);
+        for row in 0..self.num_rows {
+            for position in self.row_positions(row) {
+                for (word, source) in product.row_mut(row).iter_mut().zip(other.row(position)) {
+                    *word ^= source;
+                }
+            }
+        }
+        product
+    }
+
+    /// Transforms the matrix into its reduced row echelon form
+    /// and returns the pivot columns.
+    ///
+    /// The first rows are the pivot rows and the other rows are zero.
+    /// Pivots are found by blocks of 8 columns using the method of the
+    /// four Russians. Once the pivots of a block are found, every other
+    /// row is reduced with a single lookup in a table containing all the
+    /// combinations of the pivot rows of the block.
+    pub(crate) fn reduce_to_echelon_form(&mut self) -> Vec<usize> {
+        let mut pivots = Vec::new();
+        let mut column = 0;
+        while column < self.num_columns && pivots.len() < self.num_rows {
+            let block_start = pivots.len();
+            while column < self.num_columns
+                && pivots.len() < self.num_rows
+                && pivots.len() - block_start < M4RI_BLOCK_SIZE
+            {
+                if self.find_pivot(column, block_start, &pivots) {
+                    pivots.push(column);
+                }
+                column += 1;
+            }
+            self.eliminate_block(block_start, &pivots[block_start..]);
+        }
+        pivots
+    }
+
+    // Looks for a row, starting after the current pivots, with a one in the column
+    // once it is reduced by the pivot rows of the current block.
+    // If found, the row is moved after the current pivots and
+    // the column is eliminated from the other pivot rows of the block.
+    fn find_pivot(&mut self, column: usize, block_start: usize, pivots: &[usize]) -> bool {
+        let pivot_row = pivots.len();
+        for row in pivot_row..self.num_rows {
+            for (index, pivot) in pivots.iter().enumerate().skip(block_start) {
+                if self.get(row, *pivot) {
+                    self.add_row_to(index, row);
+                }
+            }
+            if self.get(row, column) {
+                self.swap_rows(row, pivot_row);
+                for other in block_start..pivot_row {
+                    if self.get(other, column) {
+                        self.add_row_to(pivot_row, other);
+                    }
+                }
+                return true;
+            }
+        }
+        false
+    }
+
+    // Eliminates the pivot columns of a block from every row outside the block.
+    fn eliminate_block(&mut self, block_start: usize, block_pivots: &[usize]) {
+        if block_pivots.is_empty() {
+            return;
+        }
+        let width = self.words_per_row;
+        let mut table = vec![0; (1 << block_pivots.len()) * width];
+        for mask in 1..(1usize << block_pivots.len()) {
+            let lowest = mask.trailing_zeros() as usize;
+            let previous = mask & (mask - 1);
+            for offset in 0..width {
+                table[mask * width + offset] = table[previous * width + offset]
+                    ^ self.words[(block_start + lowest) * width + offset];
+            }
+        }
+        let block = block_start..block_start + block_pivots.len();
+        for row in (0..self.num_rows).filter(|row| !block.contains(row)) {
+            let mask = block_pivots
+                .iter()
+                .enumerate()
+                .filter(|(_, pivot)| self.get(row, **pivot))
+                .fold(0, |mask, (index, _)| mask | (1 << index));
+            if mask != 0 {
+                for offset in 0..width {
+                    self.words[row * width + offset] ^= table[mask * width + offset];
+                }
+            }
+        }
+    }
+
+    fn add_row_to(&mut self, source: usize, target: usize) {
+        let width = self.words_per_row;
+        for offset in 0..width {
+            self.words[target * width + offset] ^= self.words[source * width + offset];
+        }
+    }
+
+    fn swap_rows(&mut self, first: usize, second: usize) {
+        if first != second {
+            for offset in 0..self.words_per_row {
+                self.words.swap(
+                    first * self.words_per_row + offset,
+                    second * self.words_per_row + offset,
+                );
+            }
+        }
+    }
+
+    /// Returns the rank of the matrix.
+    pub(crate) fn rank(&self) -> usize {
+        self.clone().reduce_to_echelon_form().len()
+    }
+
+    /// Returns a matrix whose rows generate the nullspace.
+    pub(crate) fn nullspace(&self) -> Self {
+        let mut echelon_form = self.clone();
+        let pivots = echelon_form.reduce_to_echelon_form();
+        echelon_form.nullspace_of_echelon_form(&pivots)
+    }
+
+    // Each free column gives a generator of the nullspace with a one at
+    // this column and ones at the pivots of the rows containing this column.
+    fn nullspace_of_echelon_form(&self, pivots: &[usize]) -> Self {
+        let free_columns: Vec<usize> = (0..self.num_columns)
+            .filter(|column| pivots.binary_search(column).is_err())
+            .collect();
+        let mut nullspace = Self::zeros(free_columns.len(), self.num_columns);
+        for (index, free_column) in free_columns.iter().enumerate() {
+            nullspace.flip(index, *free_column);
+            for (pivot_row, pivot) in pivots.iter().enumerate() {
+                if self.get(pivot_row, *free_column) {
+                    nullspace.flip(index, *pivot);
+                }
+            }
+        }
+        nullspace
+    }
+
+    /// Returns the inverse of the matrix or None if it is not invertible.
+    ///
+    /// This assumes that the matrix is square.
+    pub(crate) fn inverse(&self) -> Option<Self> {
+        let mut augmented = self.horizontal_concat_with(&Self::identity(self.num_rows));
+        let pivots = augmented.reduce_to_echelon_form();
+        if pivots.len() < self.num_rows || pivots.last() >= Some(&self.num_columns) {
+            return None;
+        }
+        Some(augmented.columns_in(self.num_columns, 2 * self.num_columns))
+    }
+
+    /// Returns the product `self * other^T` where the rows of `other`
+    /// are given by their non trivial positions.
+    ///
+    /// This is efficient when `self` is the transpose of a
+    /// matrix with many rows and `other` is sparse since each row of the
+    /// output is the sum of a few rows of `self`.
+    pub(crate) fn sparse_rows_combinations(&self, rows: &SparseBinMat) -> Self {
+        let mut output = Self::zeros(rows.number_of_rows(), self.num_columns);
+        for (index, row) in rows.rows().enumerate() {
+            let output_row = output.row_mut(index);
+            for position in row.non_trivial_positions() {
+                for (word, source) in output_row.iter_mut().zip(self.row(position)) {
+                    *word ^= source;
+                }
+            }
+        }
+        output
+    }
+}
+
+/// The reduced row echelon form `R = E A` of a matrix `A`
+/// together with the invertible transformation `E`.
+///
+/// Once computed, a system `A x = b` is solved by computing `E b`
+/// which only requires to add the columns of `E` in the support of `b`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Factorization {
+    pivots: Vec<usize>,
+    echelon_form: PackedMatrix,
+    // The transpose of E such that its rows are the columns of E.
+    transform_columns: PackedMatrix,
+}
+
+impl Factorization {
+    pub(crate) fn new(matrix: &PackedMatrix) -> Self {
+        let num_columns = matrix.num_columns;
+        let mut augmented = matrix.horizontal_concat_with(&PackedMatrix::identity(matrix.num_rows));
+        let mut pivots = augmented.reduce_to_echelon_form();
+        pivots.retain(|pivot| *pivot < num_columns);
+        Self {
+            pivots,
+            echelon_form: augmented.columns_in(0, num_columns),
+            transform_columns: augmented
+                .columns_in(num_columns, num_columns + matrix.num_rows)
+                .transposed(),
+        }
+    }
+
+    pub(crate) fn num_rows(&self) -> usize {
+        self.echelon_form.num_rows
+    }
+
+    pub(crate) fn num_columns(&self) -> usize {
+        self.echelon_form.num_columns
+    }
+
+    pub(crate) fn pivots(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    pub(crate) fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// Returns the reduced row echelon form including the zero rows.
+    pub(crate) fn echelon_form(&self) -> &PackedMatrix {
+        &self.echelon_form
+    }
+
+    pub(crate) fn transform(&self) -> PackedMatrix {
+        self.transform_columns.transposed()
+    }
+
+    pub(crate) fn nullspace(&self) -> PackedMatrix {
+        self.echelon_form.nullspace_of_echelon_form(&self.pivots)
+    }
+
+    /// Returns a solution `x` of `A x = rhs` or None
+    /// if the system is inconsistent.
+    ///
+    /// The free variables of the solution are set to zero
+    /// and the positions of the solution are sorted.
+    /// This assumes that the length of rhs is the number of rows.
+    pub(crate) fn solve(&self, rhs: &[usize]) -> Option<Vec<usize>> {
+        let mut transformed = vec![0; self.transform_columns.words_per_row];
+        for position in rhs {
+            for (word, source) in transformed
+                .iter_mut()
+                .zip(self.transform_columns.row(*position))
+            {
+                *word ^= source;
+            }
+        }
+        let is_set = |row: usize| (transformed[row / WORD_SIZE] >> (row % WORD_SIZE)) & 1 == 1;
+        if (self.rank()..self.num_rows()).any(is_set) {
+            return None;
+        }
+        Some(
+            self.pivots
+                .iter()
+                .enumerate()
+                .filter(|(row, _)| is_set(*row))
+                .map(|(_, pivot)| *pivot)
+                .collect(),
+        )
+    }
+}
+
+/// Returns the products `errors * checks^T` where each row of `errors`
+/// is an error and each row of `checks` is a parity check.
+///
+/// The errors are transposed and packed such that each syndrome bit
+/// of 64 errors is computed with a single word operation per non trivial
+/// element of the checks.
+pub(crate) fn syndromes_of(errors: &SparseBinMat, checks: &SparseBinMat) -> PyResult<SparseBinMat> {
+    if errors.number_of_columns() != checks.number_of_columns() {
+        return Err(PyValueError::new_err(format!(
+            "errors of length {} for code of length {}",
+            errors.number_of_columns(),
+            checks.number_of_columns()
+        )));
+    }
+    let packed_errors = PackedMatrix::from_sparse(&errors.transposed());
+    Ok(packed_errors
+        .sparse_rows_combinations(checks)
+        .to_sparse()
+        .transposed())
+}
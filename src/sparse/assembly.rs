@@ -0,0 +1,72 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use sparse_bin_mat::SparseBinMat;
+
+/// Returns the block diagonal matrix with left on top of right.
+pub(super) fn direct_sum(left: &SparseBinMat, right: &SparseBinMat) -> SparseBinMat {
+    let offset = left.number_of_columns();
+    let rows = left
+        .rows()
+        .map(|row| row.non_trivial_positions().collect())
+        .chain(right.rows().map(|row| {
+            row.non_trivial_positions()
+                .map(|column| column + offset)
+                .collect()
+        }))
+        .collect();
+    SparseBinMat::new(offset + right.number_of_columns(), rows)
+}
+
+/// Assembles a matrix from a grid of blocks.
+///
+/// All blocks in a block row must have the same number of rows
+/// and all blocks in a block column must have the same number of columns.
+pub(super) fn block(blocks: &[Vec<&SparseBinMat>]) -> PyResult<SparseBinMat> {
+    let widths = match blocks.first() {
+        Some(first) => first
+            .iter()
+            .map(|block| block.number_of_columns())
+            .collect::<Vec<_>>(),
+        None => return Ok(SparseBinMat::empty()),
+    };
+    let mut rows = Vec::new();
+    for (block_row, blocks) in blocks.iter().enumerate() {
+        if blocks.len() != widths.len() {
+            return Err(PyValueError::new_err(format!(
+                "block row {} has {} blocks instead of {}",
+                block_row,
+                blocks.len(),
+                widths.len()
+            )));
+        }
+        let height = blocks.first().map_or(0, |block| block.number_of_rows());
+        for (block_column, block) in blocks.iter().enumerate() {
+            if block.number_of_rows() != height || block.number_of_columns() != widths[block_column]
+            {
+                return Err(PyValueError::new_err(format!(
+                    "block ({}, {}) has shape {:?} instead of {:?}",
+                    block_row,
+                    block_column,
+                    (block.number_of_rows(), block.number_of_columns()),
+                    (height, widths[block_column])
+                )));
+            }
+        }
+        for row in 0..height {
+            let mut offset = 0;
+            let mut positions = Vec::new();
+            for block in blocks.iter() {
+                positions.extend(
+                    block
+                        .row(row)
+                        .unwrap()
+                        .non_trivial_positions()
+                        .map(|column| column + offset),
+                );
+                offset += block.number_of_columns();
+            }
+            rows.push(positions);
+        }
+    }
+    Ok(SparseBinMat::new(widths.iter().sum(), rows))
+}
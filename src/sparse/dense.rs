@@ -0,0 +1,193 @@
+// Conversions between binary matrices and numpy arrays or scipy sparse matrices.
+//
+// Dense arrays are built from the elements without copying them
+// and uint8 arrays are read without copying them.
+
+use super::{PyBinaryMatrix, PyBinaryVector};
+use numpy::{PyArray, PyReadonlyArray1, PyReadonlyArray2};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::PyNativeType;
+use sparse_bin_mat::{SparseBinMat, SparseBinSlice};
+
+pub(crate) fn to_dense(py: Python, object: &PyAny) -> PyResult<PyObject> {
+    if let Ok(matrix) = object.extract::<PyRef<PyBinaryMatrix>>() {
+        matrix_to_dense(py, &matrix.inner)
+    } else if let Ok(vector) = object.extract::<PyRef<PyBinaryVector>>() {
+        vector_to_dense(py, vector.inner.as_view())
+    } else if let Ok(vectors) = object.extract::<Vec<PyRef<PyBinaryVector>>>() {
+        vectors_to_dense(py, &vectors)
+    } else {
+        Err(PyTypeError::new_err(
+            "expected a BinaryMatrix, a BinaryVector or a sequence of BinaryVectors",
+        ))
+    }
+}
+
+pub(crate) fn matrix_to_dense(py: Python, matrix: &SparseBinMat) -> PyResult<PyObject> {
+    let mut elements = vec![0; matrix.number_of_rows() * matrix.number_of_columns()];
+    for (row, column) in matrix.non_trivial_elements() {
+        elements[row * matrix.number_of_columns() + column] = 1;
+    }
+    numpy_matrix(
+        py,
+        elements,
+        matrix.number_of_rows(),
+        matrix.number_of_columns(),
+    )
+}
+
+pub(crate) fn vector_to_dense(py: Python, vector: SparseBinSlice) -> PyResult<PyObject> {
+    let mut elements = vec![0; vector.len()];
+    for position in vector.non_trivial_positions() {
+        elements[position] = 1;
+    }
+    Ok(PyArray::from_vec(py, elements).to_object(py))
+}
+
+pub(crate) fn vectors_to_dense(
+    py: Python,
+    vectors: &[PyRef<PyBinaryVector>],
+) -> PyResult<PyObject> {
+    let length = vectors
+        .first()
+        .map(|vector| vector.inner.len())
+        .unwrap_or(0);
+    if vectors.iter().any(|vector| vector.inner.len() != length) {
+        return Err(PyValueError::new_err("vectors have different lengths"));
+    }
+    let mut elements = vec![0; vectors.len() * length];
+    for (index, vector) in vectors.iter().enumerate() {
+        for position in vector.inner.non_trivial_positions() {
+            elements[index * length + position] = 1;
+        }
+    }
+    numpy_matrix(py, elements, vectors.len(), length)
+}
+
+/// Returns the number of columns and the positions of the ones in each row
+/// of a 2D array or of the single row of a 1D array.
+///
+/// A non-empty array must have a boolean or integer dtype
+/// and all elements must be 0 or 1.
+/// Other array-like objects are first converted with `numpy.asarray`.
+pub(crate) fn dense_rows(array: &PyAny, ndim: usize) -> PyResult<(usize, Vec<Vec<usize>>)> {
+    let numpy = array.py().import("numpy")?;
+    let array = numpy.call_method1("asarray", (array,))?;
+    let array_ndim: usize = array.getattr("ndim")?.extract()?;
+    if array_ndim != ndim {
+        return Err(PyValueError::new_err(format!(
+            "expected a {}D array, not a {}D array",
+            ndim, array_ndim
+        )));
+    }
+    // The elements are validated before the cast to uint8
+    // such that values like 256 or 0.5 are not truncated to 0.
+    // Empty arrays are accepted whatever their dtype.
+    let size: usize = array.getattr("size")?.extract()?;
+    let kind: String = array.getattr("dtype")?.getattr("kind")?.extract()?;
+    if size > 0 && !["b", "i", "u"].contains(&kind.as_str()) {
+        return Err(PyValueError::new_err(
+            "array elements must be booleans or integers",
+        ));
+    }
+    if !numpy
+        .call_method1("isin", (array, [0, 1]))?
+        .call_method0("all")?
+        .is_true()?
+    {
+        return Err(PyValueError::new_err("array elements must be 0 or 1"));
+    }
+    let kwargs = PyDict::new(array.py());
+    kwargs.set_item("copy", false)?;
+    let array = array.call_method("astype", ("uint8",), Some(kwargs))?;
+    if ndim == 1 {
+        let array: PyReadonlyArray1<u8> = array.extract()?;
+        let array = array.as_array();
+        Ok((array.len(), vec![ones_of(array.iter())]))
+    } else {
+        let array: PyReadonlyArray2<u8> = array.extract()?;
+        let array = array.as_array();
+        let rows = array.outer_iter().map(|row| ones_of(row.iter())).collect();
+        Ok((array.ncols(), rows))
+    }
+}
+
+fn ones_of<'a, I>(elements: I) -> Vec<usize>
+where
+    I: Iterator<Item = &'a u8>,
+{
+    elements
+        .enumerate()
+        .filter(|(_, element)| **element == 1)
+        .map(|(position, _)| position)
+        .collect()
+}
+
+pub(crate) fn matrix_to_csr(py: Python, matrix: &SparseBinMat) -> PyResult<PyObject> {
+    let sparse = py.import("scipy.sparse")?;
+    let mut indptr = Vec::with_capacity(matrix.number_of_rows() + 1);
+    let mut indices = Vec::with_capacity(matrix.number_of_ones());
+    indptr.push(0);
+    for row in matrix.rows() {
+        indices.extend(row.non_trivial_positions());
+        indptr.push(indices.len());
+    }
+    let data = PyArray::from_vec(py, vec![1u8; indices.len()]);
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(
+        "shape",
+        (matrix.number_of_rows(), matrix.number_of_columns()),
+    )?;
+    sparse
+        .call_method("csr_matrix", ((data, indices, indptr),), Some(kwargs))
+        .map(|csr| csr.into())
+}
+
+pub(crate) fn matrix_from_csr(matrix: &PyAny) -> PyResult<SparseBinMat> {
+    let py = matrix.py();
+    let sparse = py.import("scipy.sparse")?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("copy", true)?;
+    let csr = sparse.call_method("csr_matrix", (matrix,), Some(kwargs))?;
+    csr.call_method0("sum_duplicates")?;
+    csr.call_method0("eliminate_zeros")?;
+    let (num_rows, num_columns): (usize, usize) = csr.getattr("shape")?.extract()?;
+    let indptr: Vec<usize> = csr.getattr("indptr")?.call_method0("tolist")?.extract()?;
+    let indices: Vec<usize> = csr.getattr("indices")?.call_method0("tolist")?.extract()?;
+    let data: Vec<f64> = csr
+        .getattr("data")?
+        .call_method1("astype", ("float64",))?
+        .call_method0("tolist")?
+        .extract()?;
+    if data.iter().any(|element| *element != 1.0) {
+        return Err(PyValueError::new_err("matrix elements must be 0 or 1"));
+    }
+    let rows = (0..num_rows)
+        .map(|row| indices[indptr[row]..indptr[row + 1]].to_vec())
+        .collect();
+    SparseBinMat::try_new(num_columns, rows)
+        .map_err(|error| PyValueError::new_err(error.to_string()))
+}
+
+/// Returns the matrix from either a BinaryMatrix or a 2D array-like object of 0s and 1s.
+pub(crate) fn binary_matrix_from(object: &PyAny) -> PyResult<SparseBinMat> {
+    if let Ok(matrix) = object.extract::<PyRef<PyBinaryMatrix>>() {
+        return Ok(matrix.inner.clone());
+    }
+    let (num_columns, rows) = dense_rows(object, 2)?;
+    Ok(SparseBinMat::new(num_columns, rows))
+}
+
+// Wraps the row-major elements in a 2D uint8 numpy array without copying them.
+fn numpy_matrix(
+    py: Python,
+    elements: Vec<u8>,
+    num_rows: usize,
+    num_columns: usize,
+) -> PyResult<PyObject> {
+    PyArray::from_vec(py, elements)
+        .reshape([num_rows, num_columns])
+        .map(|array| array.to_object(py))
+}
@@ -0,0 +1,299 @@
+use super::{PyBinaryMatrix, PyBinaryVector};
+use crate::packed::{Factorization, PackedMatrix};
+use bincode::{deserialize, serialize};
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyIndexError, PyNotImplementedError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::PyObjectProtocol;
+use pyo3::ToPyObject;
+use sparse_bin_mat::SparseBinVec;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A dense binary matrix where each row is packed into 64-bit words.
+///
+/// Gaussian elimination is much faster than with a `BinaryMatrix`
+/// when the matrix fills in during the elimination.
+/// It uses the method of the four Russians (M4RI)
+/// to eliminate 8 pivots at once.
+///
+/// Parameters
+/// ----------
+/// num_columns : Int
+///     The number of columns in the matrix.
+/// rows : Seq[Seq[Int]]
+///     The positions of the entries with value 1 in each row.
+///
+/// Example
+/// -------
+///     >>> from qecstruct import DenseBinaryMatrix
+///     >>> matrix = DenseBinaryMatrix(3, [[0, 1], [1, 2], [0, 2]])
+///     >>> matrix.rank()
+///     2
+///     >>> matrix.nullspace()
+///     [0, 1, 2]
+///
+/// Raises
+/// ------
+/// ValueError
+///     If a position in a row is out of bound.
+#[pyclass(name = "DenseBinaryMatrix", module = "qecstruct")]
+#[pyo3(text_signature = "(num_columns, rows)")]
+#[derive(Debug, Clone)]
+pub struct PyDenseBinaryMatrix {
+    pub(crate) inner: PackedMatrix,
+}
+
+impl From<PackedMatrix> for PyDenseBinaryMatrix {
+    fn from(inner: PackedMatrix) -> Self {
+        Self { inner }
+    }
+}
+
+#[pymethods]
+impl PyDenseBinaryMatrix {
+    #[new]
+    #[args(num_columns = "0", rows = "Vec::new()")]
+    pub fn new(num_columns: usize, rows: Vec<Vec<usize>>) -> PyResult<Self> {
+        let mut matrix = PackedMatrix::zeros(rows.len(), num_columns);
+        for (row, positions) in rows.iter().enumerate() {
+            for position in positions {
+                if *position >= num_columns {
+                    return Err(PyValueError::new_err(format!(
+                        "position {} is out of bound for {} columns",
+                        position, num_columns
+                    )));
+                }
+                if !matrix.get(row, *position) {
+                    matrix.flip(row, *position);
+                }
+            }
+        }
+        Ok(Self::from(matrix))
+    }
+
+    /// An identity matrix of the given length.
+    #[staticmethod]
+    #[pyo3(text_signature = "(length)")]
+    pub fn identity(length: usize) -> Self {
+        Self::from(PackedMatrix::identity(length))
+    }
+
+    /// A matrix filled with zeros.
+    #[staticmethod]
+    #[pyo3(text_signature = "(num_rows, num_columns)")]
+    pub fn zeros(num_rows: usize, num_columns: usize) -> Self {
+        Self::from(PackedMatrix::zeros(num_rows, num_columns))
+    }
+
+    /// Converts a sparse binary matrix to a dense binary matrix.
+    #[staticmethod]
+    #[pyo3(text_signature = "(matrix)")]
+    pub fn from_binary_matrix(matrix: &PyBinaryMatrix) -> Self {
+        Self::from(PackedMatrix::from_sparse(&matrix.inner))
+    }
+
+    /// Converts the matrix to a sparse binary matrix.
+    #[pyo3(text_signature = "(self)")]
+    pub fn to_binary_matrix(&self) -> PyBinaryMatrix {
+        self.inner.to_sparse().into()
+    }
+
+    /// Returns the number of columns in the matrix.
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_columns(&self) -> usize {
+        self.inner.num_columns()
+    }
+
+    /// Returns the number of rows in the matrix.
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_rows(&self) -> usize {
+        self.inner.num_rows()
+    }
+
+    /// Returns a tuple of the numbers of rows and columns.
+    #[pyo3(text_signature = "(self)")]
+    pub fn shape(&self) -> (usize, usize) {
+        (self.inner.num_rows(), self.inner.num_columns())
+    }
+
+    /// Checks if all the elements have value 0.
+    #[pyo3(text_signature = "(self)")]
+    pub fn is_zero(&self) -> bool {
+        self.inner.is_zero()
+    }
+
+    /// Returns the value of the element at the given row and column.
+    ///
+    /// Raises
+    /// ------
+    /// IndexError
+    ///     If the row or the column is out of bound.
+    #[pyo3(text_signature = "(self, row, column)")]
+    pub fn element(&self, row: usize, column: usize) -> PyResult<u8> {
+        if row >= self.inner.num_rows() || column >= self.inner.num_columns() {
+            return Err(PyIndexError::new_err(format!(
+                "invalid indices ({}, {}) for {} x {} matrix",
+                row,
+                column,
+                self.inner.num_rows(),
+                self.inner.num_columns()
+            )));
+        }
+        Ok(self.inner.get(row, column) as u8)
+    }
+
+    /// Returns the row at the given index as a sparse vector.
+    ///
+    /// Raises
+    /// ------
+    /// IndexError
+    ///     If the row is out of bound.
+    #[pyo3(text_signature = "(self, row)")]
+    pub fn row(&self, row: usize) -> PyResult<PyBinaryVector> {
+        if row >= self.inner.num_rows() {
+            return Err(PyIndexError::new_err(format!(
+                "invalid row {} for matrix with {} rows",
+                row,
+                self.inner.num_rows()
+            )));
+        }
+        Ok(SparseBinVec::new(self.inner.num_columns(), self.inner.row_positions(row)).into())
+    }
+
+    /// Computes the number of linearly independent rows (or columns)
+    /// of the matrix.
+    #[pyo3(text_signature = "(self)")]
+    pub fn rank(&self) -> usize {
+        self.inner.rank()
+    }
+
+    /// Returns the transpose of the matrix.
+    #[pyo3(text_signature = "(self)")]
+    pub fn transposed(&self) -> Self {
+        self.inner.transposed().into()
+    }
+
+    /// Returns the reduced row echelon form of the matrix
+    /// without the zero rows.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import DenseBinaryMatrix
+    ///     >>> matrix = DenseBinaryMatrix(3, [[0, 1], [1, 2], [0, 2]])
+    ///     >>> matrix.echelon_form()
+    ///     [0, 2]
+    ///     [1, 2]
+    #[pyo3(text_signature = "(self)")]
+    pub fn echelon_form(&self) -> Self {
+        let mut echelon_form = self.inner.clone();
+        let rank = echelon_form.reduce_to_echelon_form().len();
+        echelon_form.first_rows(rank).into()
+    }
+
+    /// Returns a matrix where the rows generate the nullspace of self.
+    #[pyo3(text_signature = "(self)")]
+    pub fn nullspace(&self) -> Self {
+        self.inner.nullspace().into()
+    }
+
+    /// Returns the inverse of the matrix.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the matrix is not square or not invertible.
+    #[pyo3(text_signature = "(self)")]
+    pub fn inverse(&self) -> PyResult<Self> {
+        if self.inner.num_rows() != self.inner.num_columns() {
+            return Err(PyValueError::new_err(format!(
+                "{} x {} matrix is not square",
+                self.inner.num_rows(),
+                self.inner.num_columns()
+            )));
+        }
+        self.inner
+            .inverse()
+            .map(Self::from)
+            .ok_or_else(|| PyValueError::new_err("matrix is not invertible"))
+    }
+
+    /// Returns a solution `x` of `self * x = rhs`
+    /// or None if the system has no solution.
+    ///
+    /// The free variables of the solution are set to 0.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the length of rhs is not the number of rows.
+    #[pyo3(text_signature = "(self, rhs)")]
+    pub fn solve(&self, rhs: &PyBinaryVector) -> PyResult<Option<PyBinaryVector>> {
+        if rhs.inner.len() != self.inner.num_rows() {
+            return Err(PyValueError::new_err(format!(
+                "rhs of length {} for matrix with {} rows",
+                rhs.inner.len(),
+                self.inner.num_rows()
+            )));
+        }
+        Ok(Factorization::new(&self.inner)
+            .solve(rhs.inner.as_slice())
+            .map(|solution| SparseBinVec::new(self.inner.num_columns(), solution).into()))
+    }
+
+    /// Returns the product of the matrix with another dense matrix.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the number of columns of self is not the number of rows of other.
+    #[pyo3(text_signature = "(self, other)")]
+    pub fn dot_with_matrix(&self, other: &Self) -> PyResult<Self> {
+        if self.inner.num_columns() != other.inner.num_rows() {
+            return Err(PyValueError::new_err(format!(
+                "can't multiply {} x {} matrix with {} x {} matrix",
+                self.inner.num_rows(),
+                self.inner.num_columns(),
+                other.inner.num_rows(),
+                other.inner.num_columns()
+            )));
+        }
+        Ok(self.inner.dot_with(&other.inner).into())
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => {
+                self.inner = deserialize(s.as_bytes()).unwrap();
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &serialize(&self.inner).unwrap()).to_object(py))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PyDenseBinaryMatrix {
+    fn __repr__(&self) -> String {
+        self.inner.to_sparse().to_string()
+    }
+
+    fn __richcmp__(&self, other: PyRef<Self>, op: CompareOp) -> PyResult<bool> {
+        match op {
+            CompareOp::Eq => Ok(self.inner == other.inner),
+            CompareOp::Ne => Ok(self.inner != other.inner),
+            _ => Err(PyNotImplementedError::new_err("not implemented")),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.inner.hash(&mut hasher);
+        hasher.finish()
+    }
+}
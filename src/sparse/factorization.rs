@@ -0,0 +1,164 @@
+use super::{PyBinaryMatrix, PyBinaryVector};
+use crate::packed::{Factorization, PackedMatrix};
+use bincode::{deserialize, serialize};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::ToPyObject;
+use sparse_bin_mat::SparseBinVec;
+
+/// The reduced row echelon form `R = E A` of a binary matrix `A`
+/// together with the invertible transformation `E`.
+///
+/// The factorization is computed once and can then be reused
+/// to solve `A x = b` for many right-hand sides `b`.
+/// Use `BinaryMatrix.factorize` to obtain a factorization.
+/// The constructor without arguments returns the factorization
+/// of an empty matrix.
+///
+/// Example
+/// -------
+///     >>> from qecstruct import BinaryMatrix, BinaryVector
+///     >>> matrix = BinaryMatrix(4, [[0, 1], [1, 2], [2, 3]])
+///     >>> factorization = matrix.factorize()
+///     >>> factorization.solve(BinaryVector(3, [0]))
+///     [0]
+///     >>> factorization.solve(BinaryVector(3, [0, 2]))
+///     [1, 2]
+#[pyclass(name = "Factorization", module = "qecstruct")]
+#[pyo3(text_signature = "()")]
+#[derive(Debug, Clone)]
+pub struct PyFactorization {
+    pub(crate) inner: Factorization,
+}
+
+impl From<Factorization> for PyFactorization {
+    fn from(inner: Factorization) -> Self {
+        Self { inner }
+    }
+}
+
+impl PyFactorization {
+    pub(crate) fn solve_positions(&self, rhs: &PyBinaryVector) -> PyResult<Option<SparseBinVec>> {
+        if rhs.inner.len() != self.inner.num_rows() {
+            return Err(PyValueError::new_err(format!(
+                "rhs of length {} for matrix with {} rows",
+                rhs.inner.len(),
+                self.inner.num_rows()
+            )));
+        }
+        Ok(self
+            .inner
+            .solve(rhs.inner.as_slice())
+            .map(|solution| SparseBinVec::new(self.inner.num_columns(), solution)))
+    }
+}
+
+#[pymethods]
+impl PyFactorization {
+    #[new]
+    pub fn new() -> Self {
+        Factorization::new(&PackedMatrix::zeros(0, 0)).into()
+    }
+
+    /// Returns the rank of the factorized matrix.
+    #[pyo3(text_signature = "(self)")]
+    pub fn rank(&self) -> usize {
+        self.inner.rank()
+    }
+
+    /// Returns the pivot columns of the reduced row echelon form.
+    ///
+    /// The row `i` of the echelon form has its first one in column `pivots()[i]`.
+    #[pyo3(text_signature = "(self)")]
+    pub fn pivots(&self) -> Vec<usize> {
+        self.inner.pivots().to_vec()
+    }
+
+    /// Returns the reduced row echelon form `R`.
+    ///
+    /// The rows after the rank are zero.
+    #[pyo3(text_signature = "(self)")]
+    pub fn echelon_form(&self) -> PyBinaryMatrix {
+        self.inner.echelon_form().to_sparse().into()
+    }
+
+    /// Returns the invertible matrix `E` such that `E A = R`.
+    #[pyo3(text_signature = "(self)")]
+    pub fn transform(&self) -> PyBinaryMatrix {
+        self.inner.transform().to_sparse().into()
+    }
+
+    /// Returns a matrix where the rows generate the nullspace of `A`.
+    #[pyo3(text_signature = "(self)")]
+    pub fn nullspace(&self) -> PyBinaryMatrix {
+        self.inner.nullspace().to_sparse().into()
+    }
+
+    /// Returns a solution `x` of `A x = rhs` or None if there is no solution.
+    ///
+    /// The solution is zero on the non pivot columns.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the length of rhs is not the number of rows of `A`.
+    #[pyo3(text_signature = "(self, rhs)")]
+    pub fn solve(&self, rhs: &PyBinaryVector) -> PyResult<Option<PyBinaryVector>> {
+        self.solve_positions(rhs)
+            .map(|solution| solution.map(PyBinaryVector::from))
+    }
+
+    /// Returns a solution of `A x = rhs` together with a matrix where the
+    /// rows generate the nullspace of `A` or None if there is no solution.
+    ///
+    /// All solutions are the sum of the returned solution
+    /// and a combination of the rows of the nullspace matrix.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the length of rhs is not the number of rows of `A`.
+    #[pyo3(text_signature = "(self, rhs)")]
+    pub fn solve_all(
+        &self,
+        rhs: &PyBinaryVector,
+    ) -> PyResult<Option<(PyBinaryVector, PyBinaryMatrix)>> {
+        Ok(self
+            .solve_positions(rhs)?
+            .map(|solution| (solution.into(), self.nullspace())))
+    }
+
+    /// Solves `A x = b` for each row `b` of the given matrix.
+    ///
+    /// Returns
+    /// -------
+    /// list[BinaryVector | None]
+    ///     A solution for each row or None for the rows without solution.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the number of columns of rhs is not the number of rows of `A`.
+    #[pyo3(text_signature = "(self, rhs)")]
+    pub fn solve_many(&self, rhs: &PyBinaryMatrix) -> PyResult<Vec<Option<PyBinaryVector>>> {
+        rhs.inner
+            .rows()
+            .map(|row| self.solve(&PyBinaryVector::from(row.to_owned())))
+            .collect()
+    }
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => deserialize(s.as_bytes())
+                .map(|inner| {
+                    self.inner = inner;
+                })
+                .map_err(|error| PyValueError::new_err(error.to_string())),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &serialize(&self.inner).unwrap()).to_object(py))
+    }
+}
@@ -0,0 +1,99 @@
+use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PySlice;
+use sparse_bin_mat::SparseBinMat;
+use std::os::raw::c_long;
+
+/// The positions selected along an axis by a Python index.
+pub(super) enum Index {
+    /// An integer, which removes the axis.
+    Single(usize),
+    /// A slice or a sequence of integers.
+    Many(Vec<usize>),
+}
+
+impl Index {
+    /// Converts an integer, a slice or a sequence of integers into positions.
+    ///
+    /// Negative integers are counted from the end of the axis.
+    pub(super) fn from_key(key: &PyAny, length: usize, axis: &str) -> PyResult<Self> {
+        if let Ok(slice) = key.downcast::<PySlice>() {
+            let indices = slice.indices(length as c_long)?;
+            let positions = (0..indices.slicelength)
+                .map(|step| (indices.start + step * indices.step) as usize)
+                .collect();
+            Ok(Index::Many(positions))
+        } else if let Ok(index) = key.extract::<isize>() {
+            normalize(index, length, axis).map(Index::Single)
+        } else if let Ok(indices) = key.extract::<Vec<isize>>() {
+            indices
+                .into_iter()
+                .map(|index| normalize(index, length, axis))
+                .collect::<PyResult<Vec<usize>>>()
+                .map(Index::Many)
+        } else {
+            Err(PyTypeError::new_err(
+                "indices must be integers, slices or sequences of integers",
+            ))
+        }
+    }
+
+    pub(super) fn positions(&self) -> &[usize] {
+        match self {
+            Index::Single(position) => std::slice::from_ref(position),
+            Index::Many(positions) => positions,
+        }
+    }
+}
+
+/// Converts a possibly negative index into a position along an axis.
+pub(super) fn normalize(index: isize, length: usize, axis: &str) -> PyResult<usize> {
+    let position = if index < 0 {
+        index + length as isize
+    } else {
+        index
+    };
+    if position < 0 || position as usize >= length {
+        return Err(PyIndexError::new_err(format!(
+            "{} {} is out of bound for length {}",
+            axis, index, length
+        )));
+    }
+    Ok(position as usize)
+}
+
+/// Checks that all positions are smaller than the given length.
+pub(super) fn validate_bounds(positions: &[usize], length: usize, axis: &str) -> PyResult<()> {
+    match positions.iter().find(|position| **position >= length) {
+        Some(position) => Err(PyValueError::new_err(format!(
+            "{} {} is out of bound for length {}",
+            axis, position, length
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Returns the submatrix with the given rows and columns in the given order.
+///
+/// This assumes that all rows and columns are in bound.
+/// Repeated rows or columns are copied.
+pub(super) fn submatrix(matrix: &SparseBinMat, rows: &[usize], columns: &[usize]) -> SparseBinMat {
+    let mut new_columns = vec![Vec::new(); matrix.number_of_columns()];
+    for (new, old) in columns.iter().enumerate() {
+        new_columns[*old].push(new);
+    }
+    let rows = rows
+        .iter()
+        .map(|row| {
+            let mut positions: Vec<usize> = matrix
+                .row(*row)
+                .unwrap()
+                .non_trivial_positions()
+                .flat_map(|column| new_columns[column].iter().cloned())
+                .collect();
+            positions.sort_unstable();
+            positions
+        })
+        .collect();
+    SparseBinMat::new(columns.len(), rows)
+}
@@ -1,12 +1,18 @@
-use crate::sparse::PyBinaryVector;
+use super::assembly::{block, direct_sum};
+use super::dense::{dense_rows, matrix_from_csr, matrix_to_csr, matrix_to_dense};
+use super::indexing::{normalize, submatrix, validate_bounds, Index};
+use crate::packed::{Factorization, PackedMatrix};
+use crate::sparse::{PyBinaryVector, PyFactorization};
+use crate::tanner_graph::TannerGraph;
 use bincode::{deserialize, serialize};
 use pyo3::class::basic::CompareOp;
 use pyo3::exceptions::{PyIndexError, PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
-use pyo3::ToPyObject;
-use pyo3::{PyIterProtocol, PyNumberProtocol, PyObjectProtocol};
-use sparse_bin_mat::SparseBinMat;
+use pyo3::types::{PyBytes, PyTuple};
+use pyo3::{PyIterProtocol, PyMappingProtocol, PyNumberProtocol, PyObjectProtocol};
+use pyo3::{PyNativeType, ToPyObject};
+use sparse_bin_mat::{SparseBinMat, SparseBinSlice, SparseBinVec};
+use std::cell::OnceCell;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
@@ -24,12 +30,12 @@ use std::hash::{Hash, Hasher};
 ///
 /// Example
 /// -------
-///     >>> from pyqec.sparse import BinaryMatrix, to_dense
+///     >>> from qecstruct import BinaryMatrix, to_dense
 ///     >>> matrix = BinaryMatrix(3, [[0, 2], [1], [0, 1]])
 ///     >>> to_dense(matrix)
 ///     array([[1, 0, 1],
 ///            [0, 1, 0],
-///            [1, 1, 0]], dtype=int32)
+///            [1, 1, 0]], dtype=uint8)
 ///
 /// Raises
 /// ------
@@ -39,11 +45,16 @@ use std::hash::{Hash, Hasher};
 #[derive(Debug, Clone)]
 pub struct PyBinaryMatrix {
     pub(crate) inner: SparseBinMat,
+    // The transposed matrix, computed on the first column access.
+    columns: OnceCell<SparseBinMat>,
 }
 
 impl From<SparseBinMat> for PyBinaryMatrix {
     fn from(inner: SparseBinMat) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            columns: OnceCell::new(),
+        }
     }
 }
 
@@ -61,12 +72,12 @@ impl PyBinaryMatrix {
     ///
     /// Example
     /// -------
-    ///     >>> from pyqec.sparse import BinaryMatrix, to_dense
+    ///     >>> from qecstruct import BinaryMatrix, to_dense
     ///     >>> matrix = BinaryMatrix.identity(3)
     ///     >>> to_dense(matrix)
     ///     array([[1, 0, 0],
     ///            [0, 1, 0],
-    ///            [0, 0, 1]], dtype=int32)
+    ///            [0, 0, 1]], dtype=uint8)
     #[staticmethod]
     #[pyo3(text_signature = "(length)")]
     pub fn identity(length: usize) -> Self {
@@ -77,17 +88,56 @@ impl PyBinaryMatrix {
     ///
     /// Example
     /// -------
-    ///     >>> from pyqec.sparse import BinaryMatrix, to_dense
+    ///     >>> from qecstruct import BinaryMatrix, to_dense
     ///     >>> matrix = BinaryMatrix.zeros(2, 3)
     ///     >>> to_dense(matrix)
     ///     array([[0, 0, 0],
-    ///            [0, 0, 0]], dtype=int32)
+    ///            [0, 0, 0]], dtype=uint8)
     #[staticmethod]
     #[pyo3(text_signature = "(num_rows, num_columns)")]
     pub fn zeros(num_rows: usize, num_columns: usize) -> Self {
         Self::from(SparseBinMat::zeros(num_rows, num_columns))
     }
 
+    /// A square circulant matrix of the given length.
+    ///
+    /// The first row has ones at the given positions
+    /// and each following row is the previous one
+    /// cyclically shifted by one position to the right.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix, to_dense
+    ///     >>> matrix = BinaryMatrix.circulant(4, [0, 1])
+    ///     >>> to_dense(matrix)
+    ///     array([[1, 1, 0, 0],
+    ///            [0, 1, 1, 0],
+    ///            [0, 0, 1, 1],
+    ///            [1, 0, 0, 1]], dtype=uint8)
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If a position is out of bound or if the positions
+    ///     are unsorted or contain duplicates.
+    #[staticmethod]
+    #[pyo3(text_signature = "(length, positions)")]
+    pub fn circulant(length: usize, positions: Vec<usize>) -> PyResult<Self> {
+        SparseBinSlice::try_new(length, positions.as_slice())
+            .map_err(|error| PyValueError::new_err(error.to_string()))?;
+        let rows = (0..length)
+            .map(|shift| {
+                let mut row: Vec<usize> = positions
+                    .iter()
+                    .map(|position| (position + shift) % length)
+                    .collect();
+                row.sort_unstable();
+                row
+            })
+            .collect();
+        Ok(Self::from(SparseBinMat::new(length, rows)))
+    }
+
     /// An empty matrix.
     ///
     /// Mostly useful as a placeholder since
@@ -208,6 +258,314 @@ impl PyBinaryMatrix {
         self.inner.nullspace().into()
     }
 
+    /// Returns the reduced row echelon form of the matrix without the zero rows.
+    ///
+    /// Each row has a one in its pivot column and all other rows
+    /// are zero in this column.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(4, [[0, 1, 2], [1, 3], [0, 2], [0, 2, 3]])
+    ///     >>> matrix.reduced_echelon_form()
+    ///     [0, 2]
+    ///     [1]
+    ///     [3]
+    #[pyo3(text_signature = "(self)")]
+    pub fn reduced_echelon_form(&self) -> Self {
+        let factorization = self.packed_factorization();
+        factorization
+            .echelon_form()
+            .first_rows(factorization.rank())
+            .to_sparse()
+            .into()
+    }
+
+    /// Returns the pivot columns of the reduced row echelon form.
+    ///
+    /// These columns are linearly independent and form an information set
+    /// of the code generated by the rows of the matrix.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(4, [[0, 1], [0, 1], [2, 3]])
+    ///     >>> matrix.pivots()
+    ///     [0, 2]
+    #[pyo3(text_signature = "(self)")]
+    pub fn pivots(&self) -> Vec<usize> {
+        self.packed_factorization().pivots().to_vec()
+    }
+
+    /// Returns the permutation of the columns placing the pivot columns first
+    /// followed by the other columns in increasing order.
+    ///
+    /// Applying this permutation to the columns of the
+    /// reduced row echelon form gives a matrix of the form `[I | A]`.
+    #[pyo3(text_signature = "(self)")]
+    pub fn pivot_permutation(&self) -> Vec<usize> {
+        let pivots = self.pivots();
+        let others = (0..self.inner.number_of_columns())
+            .filter(|column| pivots.binary_search(column).is_err())
+            .collect::<Vec<usize>>();
+        pivots.into_iter().chain(others).collect()
+    }
+
+    /// Returns the reduced row echelon form `R`, including the zero rows,
+    /// the pivot columns and the invertible matrix `E` such that `E * self = R`.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(3, [[0, 1], [0, 1, 2]])
+    ///     >>> echelon_form, pivots, transform = matrix.reduced_echelon_form_with_transform()
+    ///     >>> pivots
+    ///     [0, 2]
+    ///     >>> transform.dot_with_matrix(matrix) == echelon_form
+    ///     True
+    #[pyo3(text_signature = "(self)")]
+    pub fn reduced_echelon_form_with_transform(&self) -> (Self, Vec<usize>, Self) {
+        let factorization = self.packed_factorization();
+        (
+            factorization.echelon_form().to_sparse().into(),
+            factorization.pivots().to_vec(),
+            factorization.transform().to_sparse().into(),
+        )
+    }
+
+    /// Returns the inverse of a square matrix.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(2, [[0, 1], [1]])
+    ///     >>> matrix.inverse()
+    ///     [0, 1]
+    ///     [1]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the matrix is not square or not invertible.
+    #[pyo3(text_signature = "(self)")]
+    pub fn inverse(&self) -> PyResult<Self> {
+        if self.inner.number_of_rows() != self.inner.number_of_columns() {
+            return Err(PyValueError::new_err(format!(
+                "{} x {} matrix is not square",
+                self.inner.number_of_rows(),
+                self.inner.number_of_columns()
+            )));
+        }
+        self.right_inverse()
+    }
+
+    /// Returns a matrix `B` such that `self * B` is the identity.
+    ///
+    /// The rows of `B` are zero outside the pivot columns of self.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the rows of the matrix are not linearly independent.
+    #[pyo3(text_signature = "(self)")]
+    pub fn right_inverse(&self) -> PyResult<Self> {
+        let factorization = self.packed_factorization();
+        if factorization.rank() < self.inner.number_of_rows() {
+            return Err(PyValueError::new_err(format!(
+                "matrix with {} rows has rank {}",
+                self.inner.number_of_rows(),
+                factorization.rank()
+            )));
+        }
+        // Since R = E A is the identity on the pivot columns,
+        // the inverse is E with row i moved to the i-th pivot.
+        let transform = factorization.transform();
+        let mut rows = vec![Vec::new(); self.inner.number_of_columns()];
+        for (row, pivot) in factorization.pivots().iter().enumerate() {
+            rows[*pivot] = transform.row_positions(row);
+        }
+        Ok(Self::from(SparseBinMat::new(
+            self.inner.number_of_rows(),
+            rows,
+        )))
+    }
+
+    /// Returns a matrix `B` such that `B * self` is the identity.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the columns of the matrix are not linearly independent.
+    #[pyo3(text_signature = "(self)")]
+    pub fn left_inverse(&self) -> PyResult<Self> {
+        self.transposed()
+            .right_inverse()
+            .map(|inverse| inverse.transposed())
+            .map_err(|_| {
+                PyValueError::new_err(format!(
+                    "matrix with {} columns has rank {}",
+                    self.inner.number_of_columns(),
+                    self.packed_factorization().rank()
+                ))
+            })
+    }
+
+    /// Computes the reduced row echelon form of the matrix together with
+    /// the transformation to obtain it.
+    ///
+    /// The factorization can be reused to solve linear systems
+    /// with many right-hand sides.
+    #[pyo3(text_signature = "(self)")]
+    pub fn factorize(&self) -> PyFactorization {
+        self.packed_factorization().into()
+    }
+
+    /// Returns a solution `x` of `self * x = rhs`
+    /// or None if the system has no solution.
+    ///
+    /// The solution is zero on the non pivot columns.
+    /// Use `factorize` to solve many systems with the same matrix.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix, BinaryVector
+    ///     >>> matrix = BinaryMatrix(3, [[0, 1], [1, 2]])
+    ///     >>> matrix.solve(BinaryVector(2, [0]))
+    ///     [0]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the length of rhs is not the number of rows.
+    #[pyo3(text_signature = "(self, rhs)")]
+    pub fn solve(&self, rhs: &PyBinaryVector) -> PyResult<Option<PyBinaryVector>> {
+        self.factorize().solve(rhs)
+    }
+
+    /// Returns a solution of `self * x = rhs` together with a matrix
+    /// where the rows generate the nullspace of self
+    /// or None if the system has no solution.
+    ///
+    /// All solutions are the sum of the returned solution
+    /// and a combination of the rows of the nullspace matrix.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the length of rhs is not the number of rows.
+    #[pyo3(text_signature = "(self, rhs)")]
+    pub fn solve_all(
+        &self,
+        rhs: &PyBinaryVector,
+    ) -> PyResult<Option<(PyBinaryVector, PyBinaryMatrix)>> {
+        self.factorize().solve_all(rhs)
+    }
+
+    /// Returns the length of the shortest cycle of the Tanner graph where
+    /// the columns are the bits and the rows are the checks
+    /// or -1 if the graph has no cycle.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(4, [[0, 1, 2], [1, 2, 3], [0, 3]])
+    ///     >>> matrix.girth()
+    ///     4
+    #[pyo3(text_signature = "(self)")]
+    pub fn girth(&self) -> i64 {
+        TannerGraph::from_parity_check_matrix(&self.inner)
+            .girth()
+            .map(|girth| girth as i64)
+            .unwrap_or(-1)
+    }
+
+    /// Returns the number of cycles of the given length
+    /// in the Tanner graph where
+    /// the columns are the bits and the rows are the checks.
+    ///
+    /// Caution
+    /// -------
+    /// The execution time scales exponentially with the length.
+    /// This is meant to count short cycles of length 4, 6 or 8.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the length is odd or smaller than 4.
+    #[pyo3(text_signature = "(self, length)")]
+    pub fn num_cycles(&self, length: usize) -> PyResult<usize> {
+        if length < 4 || length % 2 == 1 {
+            return Err(PyValueError::new_err(format!(
+                "cycles of a bipartite graph have even length of at least 4, not {}",
+                length
+            )));
+        }
+        Ok(TannerGraph::from_parity_check_matrix(&self.inner).num_cycles(length))
+    }
+
+    /// Returns the stopping sets of at most the given size.
+    ///
+    /// A stopping set is a set of columns such that
+    /// no row is connected to exactly one of them.
+    /// Only the stopping sets inducing a connected subgraph
+    /// of the Tanner graph are returned
+    /// since any other stopping set is a disjoint union of those.
+    ///
+    /// Returns
+    /// -------
+    /// list[list[int]]
+    ///     The columns of each stopping set
+    ///     sorted by size and then lexicographically.
+    ///
+    /// Caution
+    /// -------
+    /// The execution time scales exponentially with the size.
+    #[pyo3(text_signature = "(self, max_size)")]
+    pub fn stopping_sets(&self, max_size: usize) -> Vec<Vec<usize>> {
+        TannerGraph::from_parity_check_matrix(&self.inner).stopping_sets(max_size)
+    }
+
+    /// Returns the (a, b) trapping sets with `a <= max_size` and `b <= max_unsatisfied`.
+    ///
+    /// A (a, b) trapping set is a set of `a` columns inducing
+    /// a connected subgraph of the Tanner graph where `b` rows
+    /// are connected to an odd number of them.
+    /// These are the rows that are unsatisfied if the columns are flipped.
+    ///
+    /// Returns
+    /// -------
+    /// list[tuple[list[int], int]]
+    ///     The columns of each trapping set together with the number
+    ///     of unsatisfied rows sorted by size and then lexicographically.
+    ///
+    /// Caution
+    /// -------
+    /// The execution time scales exponentially with the size.
+    #[pyo3(text_signature = "(self, max_size, max_unsatisfied)")]
+    pub fn trapping_sets(
+        &self,
+        max_size: usize,
+        max_unsatisfied: usize,
+    ) -> Vec<(Vec<usize>, usize)> {
+        TannerGraph::from_parity_check_matrix(&self.inner).trapping_sets(max_size, max_unsatisfied)
+    }
+
+    /// Returns the number of columns of each weight.
+    ///
+    /// The element at index `w` is the number of columns of weight `w`.
+    #[pyo3(text_signature = "(self)")]
+    pub fn column_degree_distribution(&self) -> Vec<usize> {
+        TannerGraph::from_parity_check_matrix(&self.inner).bit_degree_distribution()
+    }
+
+    /// Returns the number of rows of each weight.
+    ///
+    /// The element at index `w` is the number of rows of weight `w`.
+    #[pyo3(text_signature = "(self)")]
+    pub fn row_degree_distribution(&self) -> Vec<usize> {
+        TannerGraph::from_parity_check_matrix(&self.inner).check_degree_distribution()
+    }
+
     /// Check if the given element has value 0.
     ///
     /// Raises
@@ -284,6 +642,72 @@ impl PyBinaryMatrix {
         self.inner.vertical_concat_with(&other.inner).into()
     }
 
+    /// Returns the Kronecker product of self and other matrix.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(2, [[1], [0]])
+    ///     >>> matrix.kron(BinaryMatrix(3, [[0, 1], [1, 2]]))
+    ///     [3, 4]
+    ///     [4, 5]
+    ///     [0, 1]
+    ///     [1, 2]
+    #[pyo3(text_signature = "(self, other)")]
+    pub fn kron(&self, other: &Self) -> Self {
+        self.inner.kron_with(&other.inner).into()
+    }
+
+    /// Returns the block diagonal matrix with self on top of other matrix.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(2, [[0, 1]])
+    ///     >>> matrix.direct_sum(BinaryMatrix.identity(2))
+    ///     [0, 1]
+    ///     [2]
+    ///     [3]
+    #[pyo3(text_signature = "(self, other)")]
+    pub fn direct_sum(&self, other: &Self) -> Self {
+        direct_sum(&self.inner, &other.inner).into()
+    }
+
+    /// Assembles a matrix from a grid of blocks.
+    ///
+    /// Parameters
+    /// ----------
+    /// blocks : Seq[Seq[BinaryMatrix]]
+    ///     Each inner sequence is a row of blocks.
+    ///     All blocks in a block row must have the same number of rows
+    ///     and all blocks in a block column must have the same number of columns.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> a = BinaryMatrix(2, [[0, 1]])
+    ///     >>> BinaryMatrix.block([
+    ///     ...     [a, BinaryMatrix.zeros(1, 1)],
+    ///     ...     [BinaryMatrix.identity(2), BinaryMatrix(1, [[0], []])],
+    ///     ... ])
+    ///     [0, 1]
+    ///     [0, 2]
+    ///     [1]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The shapes of the blocks are incompatible.
+    #[staticmethod]
+    #[pyo3(text_signature = "(blocks)")]
+    pub fn block(blocks: Vec<Vec<PyRef<Self>>>) -> PyResult<Self> {
+        let blocks: Vec<Vec<&SparseBinMat>> = blocks
+            .iter()
+            .map(|row| row.iter().map(|block| &block.inner).collect())
+            .collect();
+        block(&blocks).map(Self::from)
+    }
+
     /// Returns the dot product between self and the given vector.
     ///
     /// Example
@@ -393,6 +817,246 @@ impl PyBinaryMatrix {
             })
     }
 
+    /// Returns the given column as a BinaryVector.
+    ///
+    /// Columns are computed once on the first column access
+    /// and cached until the matrix is modified.
+    ///
+    /// Raises
+    /// ------
+    /// IndexError
+    ///   The column is out of bound.
+    #[pyo3(text_signature = "(self, column)")]
+    pub fn column(&self, column: usize) -> PyResult<PyBinaryVector> {
+        self.columns_matrix()
+            .row(column)
+            .map(|column| column.to_owned().into())
+            .ok_or_else(|| {
+                PyIndexError::new_err(format!(
+                    "invalid column {} for {} x {} matrix",
+                    column,
+                    self.num_rows(),
+                    self.num_columns()
+                ))
+            })
+    }
+
+    /// Returns the number of ones in each column.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(3, [[0, 2], [1], [0, 1]])
+    ///     >>> matrix.column_weights()
+    ///     [2, 2, 1]
+    #[pyo3(text_signature = "(self)")]
+    pub fn column_weights(&self) -> Vec<usize> {
+        self.columns_matrix()
+            .rows()
+            .map(|column| column.weight())
+            .collect()
+    }
+
+    /// Returns the number of ones in each row.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(3, [[0, 2], [1], [0, 1]])
+    ///     >>> matrix.row_weights()
+    ///     [2, 1, 2]
+    #[pyo3(text_signature = "(self)")]
+    pub fn row_weights(&self) -> Vec<usize> {
+        self.inner.rows().map(|row| row.weight()).collect()
+    }
+
+    /// Returns a new matrix keeping only the given rows.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(4, [[0, 1], [1, 2], [2, 3]])
+    ///     >>> matrix.keep_only_rows([0, 2])
+    ///     [0, 1]
+    ///     [2, 3]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The rows are out of bound, unsorted or duplicated.
+    #[pyo3(text_signature = "(self, rows)")]
+    pub fn keep_only_rows(&self, rows: Vec<usize>) -> PyResult<Self> {
+        self.inner
+            .keep_only_rows(&rows)
+            .map(Self::from)
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    /// Returns a new matrix keeping only the given columns.
+    ///
+    /// Columns are relabeled to fit the new number of columns.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(4, [[0, 1], [1, 2], [2, 3]])
+    ///     >>> matrix.keep_only_columns([1, 3])
+    ///     [0]
+    ///     [0]
+    ///     [1]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The columns are out of bound, unsorted or duplicated.
+    #[pyo3(text_signature = "(self, columns)")]
+    pub fn keep_only_columns(&self, columns: Vec<usize>) -> PyResult<Self> {
+        self.inner
+            .keep_only_columns(&columns)
+            .map(Self::from)
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    /// Returns a new matrix where the given rows are removed.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(4, [[0, 1], [1, 2], [2, 3]])
+    ///     >>> matrix.without_rows([1])
+    ///     [0, 1]
+    ///     [2, 3]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The rows are out of bound.
+    #[pyo3(text_signature = "(self, rows)")]
+    pub fn without_rows(&self, rows: Vec<usize>) -> PyResult<Self> {
+        validate_bounds(&rows, self.num_rows(), "row")?;
+        self.inner
+            .without_rows(&rows)
+            .map(Self::from)
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    /// Returns a new matrix where the given columns are removed.
+    ///
+    /// Columns are relabeled to fit the new number of columns.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(4, [[0, 1], [1, 2], [2, 3]])
+    ///     >>> matrix.without_columns([0, 2])
+    ///     [0]
+    ///     [0]
+    ///     [1]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The columns are out of bound.
+    #[pyo3(text_signature = "(self, columns)")]
+    pub fn without_columns(&self, columns: Vec<usize>) -> PyResult<Self> {
+        validate_bounds(&columns, self.num_columns(), "column")?;
+        self.inner
+            .without_columns(&columns)
+            .map(Self::from)
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    /// Returns a new matrix with permuted columns.
+    ///
+    /// The column i of the new matrix is the column permutation[i] of self.
+    /// This is the same convention as numpy's `matrix[:, permutation]`.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(3, [[0, 1], [2]])
+    ///     >>> matrix.permute_columns([2, 0, 1])
+    ///     [1, 2]
+    ///     [0]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The permutation is not a permutation of the columns.
+    #[pyo3(text_signature = "(self, permutation)")]
+    pub fn permute_columns(&self, permutation: Vec<usize>) -> PyResult<Self> {
+        let mut sorted = permutation.clone();
+        sorted.sort_unstable();
+        if !sorted.into_iter().eq(0..self.num_columns()) {
+            return Err(PyValueError::new_err(format!(
+                "{:?} is not a permutation of {} columns",
+                permutation,
+                self.num_columns()
+            )));
+        }
+        let rows = (0..self.num_rows()).collect::<Vec<_>>();
+        Ok(submatrix(&self.inner, &rows, &permutation).into())
+    }
+
+    /// Swaps the two given rows in place.
+    ///
+    /// Raises
+    /// ------
+    /// IndexError
+    ///   A row is out of bound.
+    #[pyo3(text_signature = "(self, first, second)")]
+    pub fn swap_rows(&mut self, first: usize, second: usize) -> PyResult<()> {
+        for row in [first, second].iter() {
+            if *row >= self.num_rows() {
+                return Err(PyIndexError::new_err(format!(
+                    "invalid row {} for {} x {} matrix",
+                    row,
+                    self.num_rows(),
+                    self.num_columns()
+                )));
+            }
+        }
+        let mut rows = (0..self.num_rows()).collect::<Vec<_>>();
+        rows.swap(first, second);
+        let columns = (0..self.num_columns()).collect::<Vec<_>>();
+        self.replace_inner(submatrix(&self.inner, &rows, &columns));
+        Ok(())
+    }
+
+    /// Sets the element at the given row and column to 1 in place.
+    ///
+    /// Raises
+    /// ------
+    /// IndexError
+    ///   The row or column is out of bound.
+    #[pyo3(text_signature = "(self, row, column)")]
+    pub fn set(&mut self, row: usize, column: usize) -> PyResult<()> {
+        self.emplace_at(1, row, column)
+    }
+
+    /// Sets the element at the given row and column to 0 in place.
+    ///
+    /// Raises
+    /// ------
+    /// IndexError
+    ///   The row or column is out of bound.
+    #[pyo3(text_signature = "(self, row, column)")]
+    pub fn clear(&mut self, row: usize, column: usize) -> PyResult<()> {
+        self.emplace_at(0, row, column)
+    }
+
+    /// Flips the element at the given row and column in place.
+    ///
+    /// Raises
+    /// ------
+    /// IndexError
+    ///   The row or column is out of bound.
+    #[pyo3(text_signature = "(self, row, column)")]
+    pub fn flip(&mut self, row: usize, column: usize) -> PyResult<()> {
+        let value = self.element(row, column)?;
+        self.emplace_at(1 - value, row, column)
+    }
+
     // Returns an iterator throught all rows.
     //
     // Example
@@ -407,7 +1071,26 @@ impl PyBinaryMatrix {
     #[pyo3(text_signature = "(self)")]
     pub fn rows(&self) -> PyRows {
         PyRows {
-            matrix: self.clone(),
+            matrix: self.inner.clone(),
+            row_index: 0,
+        }
+    }
+
+    /// Returns an iterator throught all columns.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(3, [[0, 2], [1], [0, 1]])
+    ///     >>> for column in matrix.columns():
+    ///     ...    print(column)
+    ///     [0, 2]
+    ///     [1, 2]
+    ///     [0]
+    #[pyo3(text_signature = "(self)")]
+    pub fn columns(&self) -> PyRows {
+        PyRows {
+            matrix: self.columns_matrix().clone(),
             row_index: 0,
         }
     }
@@ -434,10 +1117,58 @@ impl PyBinaryMatrix {
         }
     }
 
+    /// Returns the matrix as a 2D numpy array of uint8.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinaryMatrix
+    ///     >>> matrix = BinaryMatrix(3, [[0, 2], [1]])
+    ///     >>> matrix.to_dense()
+    ///     array([[1, 0, 1],
+    ///            [0, 1, 0]], dtype=uint8)
+    #[pyo3(text_signature = "(self)")]
+    pub fn to_dense(&self, py: Python) -> PyResult<PyObject> {
+        matrix_to_dense(py, &self.inner)
+    }
+
+    /// Constructs a matrix from a 2D array-like object of 0s and 1s.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the array is not 2D or if an element is not 0 or 1.
+    #[staticmethod]
+    #[pyo3(text_signature = "(array)")]
+    pub fn from_dense(array: &PyAny) -> PyResult<Self> {
+        let (num_columns, rows) = dense_rows(array, 2)?;
+        Ok(Self::from(SparseBinMat::new(num_columns, rows)))
+    }
+
+    /// Returns the matrix as a scipy.sparse.csr_matrix of uint8.
+    #[pyo3(text_signature = "(self)")]
+    pub fn to_csr(&self, py: Python) -> PyResult<PyObject> {
+        matrix_to_csr(py, &self.inner)
+    }
+
+    /// Constructs a matrix from a scipy sparse matrix.
+    ///
+    /// The matrix is converted to the CSR format and duplicated
+    /// entries are summed before the conversion.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If an element is not 0 or 1.
+    #[staticmethod]
+    #[pyo3(text_signature = "(matrix)")]
+    pub fn from_csr(matrix: &PyAny) -> PyResult<Self> {
+        matrix_from_csr(matrix).map(Self::from)
+    }
+
     pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
         match state.extract::<&PyBytes>(py) {
             Ok(s) => {
-                self.inner = deserialize(s.as_bytes()).unwrap();
+                self.replace_inner(deserialize(s.as_bytes()).unwrap());
                 Ok(())
             }
             Err(e) => Err(e),
@@ -449,6 +1180,41 @@ impl PyBinaryMatrix {
     }
 }
 
+impl PyBinaryMatrix {
+    fn packed_factorization(&self) -> Factorization {
+        Factorization::new(&PackedMatrix::from_sparse(&self.inner))
+    }
+
+    fn emplace_at(&mut self, value: u8, row: usize, column: usize) -> PyResult<()> {
+        self.element(row, column)?;
+        let matrix = std::mem::replace(&mut self.inner, SparseBinMat::empty());
+        self.replace_inner(matrix.emplace_at(value, row, column));
+        Ok(())
+    }
+
+    // Every in place modification must go through this to invalidate the cached columns.
+    fn replace_inner(&mut self, inner: SparseBinMat) {
+        self.inner = inner;
+        self.columns = OnceCell::new();
+    }
+
+    fn columns_matrix(&self) -> &SparseBinMat {
+        self.columns.get_or_init(|| self.inner.transposed())
+    }
+
+    // This assumes that the rows and column are in bound.
+    fn column_at(&self, rows: &[usize], column: usize) -> PyBinaryVector {
+        let column = self.columns_matrix().row(column).unwrap();
+        let positions = rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| column.is_one_at(**row) == Some(true))
+            .map(|(position, _)| position)
+            .collect();
+        SparseBinVec::new(rows.len(), positions).into()
+    }
+}
+
 #[pyproto]
 impl PyObjectProtocol for PyBinaryMatrix {
     fn __repr__(&self) -> String {
@@ -457,8 +1223,8 @@ impl PyObjectProtocol for PyBinaryMatrix {
 
     fn __richcmp__(&self, other: PyRef<Self>, op: CompareOp) -> PyResult<bool> {
         match op {
-            CompareOp::Eq => Ok(&self.inner == &other.inner),
-            CompareOp::Ne => Ok(&self.inner != &other.inner),
+            CompareOp::Eq => Ok(self.inner == other.inner),
+            CompareOp::Ne => Ok(self.inner != other.inner),
             _ => Err(PyNotImplementedError::new_err("not implemented")),
         }
     }
@@ -480,9 +1246,59 @@ impl PyNumberProtocol for PyBinaryMatrix {
     }
 }
 
+#[pyproto]
+impl PyMappingProtocol for PyBinaryMatrix {
+    fn __len__(&self) -> usize {
+        self.num_rows()
+    }
+
+    fn __getitem__(&self, key: &PyAny) -> PyResult<PyObject> {
+        let py = key.py();
+        let (rows, columns) = match key.downcast::<PyTuple>() {
+            Ok(tuple) if tuple.len() == 2 => (
+                Index::from_key(tuple.get_item(0), self.num_rows(), "row")?,
+                Index::from_key(tuple.get_item(1), self.num_columns(), "column")?,
+            ),
+            Ok(_) => {
+                return Err(PyIndexError::new_err(
+                    "matrices are indexed with at most 2 indices",
+                ))
+            }
+            Err(_) => (
+                Index::from_key(key, self.num_rows(), "row")?,
+                Index::Many((0..self.num_columns()).collect()),
+            ),
+        };
+        Ok(match (rows, columns) {
+            (Index::Single(row), Index::Single(column)) => self.element(row, column)?.into_py(py),
+            (Index::Single(row), columns) => {
+                PyBinaryMatrix::from(submatrix(&self.inner, &[row], columns.positions()))
+                    .row(0)?
+                    .into_py(py)
+            }
+            (Index::Many(rows), Index::Single(column)) => self.column_at(&rows, column).into_py(py),
+            (Index::Many(rows), columns) => {
+                PyBinaryMatrix::from(submatrix(&self.inner, &rows, columns.positions())).into_py(py)
+            }
+        })
+    }
+
+    fn __setitem__(&mut self, key: (isize, isize), value: u8) -> PyResult<()> {
+        if value > 1 {
+            return Err(PyValueError::new_err(format!(
+                "{} is not a binary value",
+                value
+            )));
+        }
+        let row = normalize(key.0, self.num_rows(), "row")?;
+        let column = normalize(key.1, self.num_columns(), "column")?;
+        self.emplace_at(value, row, column)
+    }
+}
+
 #[pyclass]
 pub struct PyRows {
-    matrix: PyBinaryMatrix,
+    matrix: SparseBinMat,
     row_index: usize,
 }
 
@@ -495,7 +1311,6 @@ impl PyIterProtocol for PyRows {
     fn __next__(mut slf: PyRefMut<Self>) -> Option<PyBinaryVector> {
         let row = slf
             .matrix
-            .inner
             .row(slf.row_index)
             .map(|row| row.to_owned().into());
         slf.row_index += 1;
@@ -1,5 +1,21 @@
+mod assembly;
+
+mod dense;
+pub(crate) use dense::{binary_matrix_from, to_dense};
+
+mod dense_matrix;
+pub use dense_matrix::PyDenseBinaryMatrix;
+
+mod factorization;
+pub use factorization::PyFactorization;
+
+mod indexing;
+
 mod matrix;
 pub use matrix::PyBinaryMatrix;
 
+mod subspace;
+pub use subspace::PyBinarySubspace;
+
 mod vector;
 pub use vector::PyBinaryVector;
@@ -0,0 +1,330 @@
+use super::{PyBinaryMatrix, PyBinaryVector};
+use crate::packed::PackedMatrix;
+use crate::symplectic::RowBasis;
+use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::{PyNotImplementedError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::ToPyObject;
+use pyo3::{PyObjectProtocol, PySequenceProtocol};
+use sparse_bin_mat::{SparseBinMat, SparseBinVec};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// A linear subspace of binary vectors.
+///
+/// The subspace is represented by the reduced row echelon form
+/// of its generators, which is unique for a given subspace.
+/// Thus, two subspaces are equal if and only if they have the same basis.
+///
+/// Parameters
+/// ----------
+/// length : Int
+///     The length of the vectors in the subspace.
+/// generators : Seq[Seq[Int]]
+///     The positions of the entries with value 1 in each generator.
+///     The generators don't need to be linearly independent.
+///
+/// Example
+/// -------
+///     >>> from qecstruct import BinarySubspace, BinaryVector
+///     >>> subspace = BinarySubspace(4, [[0, 1], [1, 2], [0, 2]])
+///     >>> subspace.dimension()
+///     2
+///     >>> BinaryVector(4, [0, 2]) in subspace
+///     True
+///     >>> subspace.basis()
+///     [0, 2]
+///     [1, 2]
+///
+/// Raises
+/// ------
+/// ValueError
+///     If a position in a generator is out of bound.
+#[pyclass(name = "BinarySubspace", module = "qecstruct")]
+#[pyo3(text_signature = "(length, generators)")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PyBinarySubspace {
+    basis: SparseBinMat,
+}
+
+impl PyBinarySubspace {
+    /// Returns the subspace spanned by the rows of the matrix.
+    pub(crate) fn from_generators(generators: &SparseBinMat) -> Self {
+        let mut echelon_form = PackedMatrix::from_sparse(generators);
+        let rank = echelon_form.reduce_to_echelon_form().len();
+        Self {
+            basis: echelon_form.first_rows(rank).to_sparse(),
+        }
+    }
+
+    /// Returns the subspace orthogonal to all rows of the matrix.
+    pub(crate) fn orthogonal_to(matrix: &SparseBinMat) -> Self {
+        Self::from_generators(&PackedMatrix::from_sparse(matrix).nullspace().to_sparse())
+    }
+
+    /// Returns the basis in reduced row echelon form.
+    pub(crate) fn basis_matrix(&self) -> &SparseBinMat {
+        &self.basis
+    }
+
+    fn length(&self) -> usize {
+        self.basis.number_of_columns()
+    }
+
+    /// Checks if a vector of the same length is in the subspace.
+    ///
+    /// In reduced echelon form, a vector is in the span if and only if
+    /// it is equal to the sum of the basis vectors with a pivot in its support.
+    pub(crate) fn contains(&self, vector: &SparseBinVec) -> bool {
+        let mut combination = SparseBinVec::zeros(self.length());
+        for row in self.basis.rows() {
+            let pivot = row.non_trivial_positions().next().unwrap();
+            if vector.is_one_at(pivot) == Some(true) {
+                combination = combination.bitwise_xor_with(&row).unwrap();
+            }
+        }
+        combination == *vector
+    }
+
+    fn check_same_length(&self, other: &Self) -> PyResult<()> {
+        if self.length() != other.length() {
+            return Err(PyValueError::new_err(format!(
+                "subspaces of vectors of length {} and {} are incompatible",
+                self.length(),
+                other.length()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl PyBinarySubspace {
+    #[new]
+    #[args(length = "0", generators = "Vec::new()")]
+    pub fn new(length: usize, generators: Vec<Vec<usize>>) -> PyResult<Self> {
+        SparseBinMat::try_new(length, generators)
+            .map(|generators| Self::from_generators(&generators))
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    /// Returns the subspace spanned by the rows of a matrix.
+    #[staticmethod]
+    #[pyo3(text_signature = "(matrix)")]
+    pub fn row_space(matrix: &PyBinaryMatrix) -> Self {
+        Self::from_generators(&matrix.inner)
+    }
+
+    /// Returns the subspace of vectors orthogonal to all rows of a matrix.
+    ///
+    /// This is the kernel of the matrix.
+    #[staticmethod]
+    #[pyo3(text_signature = "(matrix)")]
+    pub fn kernel(matrix: &PyBinaryMatrix) -> Self {
+        Self::orthogonal_to(&matrix.inner)
+    }
+
+    /// Returns the subspace containing only the zero vector.
+    #[staticmethod]
+    #[pyo3(text_signature = "(length)")]
+    pub fn zero(length: usize) -> Self {
+        Self {
+            basis: SparseBinMat::zeros(0, length),
+        }
+    }
+
+    /// Returns the subspace containing all vectors of the given length.
+    #[staticmethod]
+    #[pyo3(text_signature = "(length)")]
+    pub fn full(length: usize) -> Self {
+        Self {
+            basis: SparseBinMat::identity(length),
+        }
+    }
+
+    /// Returns the reduced row echelon form of the generators.
+    #[pyo3(text_signature = "(self)")]
+    pub fn basis(&self) -> PyBinaryMatrix {
+        self.basis.clone().into()
+    }
+
+    /// Returns the pivot column of each basis vector.
+    #[pyo3(text_signature = "(self)")]
+    pub fn pivots(&self) -> Vec<usize> {
+        self.basis
+            .rows()
+            .map(|row| row.non_trivial_positions().next().unwrap())
+            .collect()
+    }
+
+    /// Returns the number of vectors in a basis of the subspace.
+    #[pyo3(text_signature = "(self)")]
+    pub fn dimension(&self) -> usize {
+        self.basis.number_of_rows()
+    }
+
+    /// Returns the length of the vectors in the subspace.
+    #[pyo3(name = "length", text_signature = "(self)")]
+    pub fn py_length(&self) -> usize {
+        self.length()
+    }
+
+    /// Checks if the given vector is in the subspace.
+    ///
+    /// This is also available with the `in` operator.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The vector length is not the length of the subspace.
+    #[pyo3(text_signature = "(self, vector)")]
+    pub fn has_vector(&self, vector: &PyBinaryVector) -> PyResult<bool> {
+        if vector.inner.len() != self.length() {
+            return Err(PyValueError::new_err(format!(
+                "vector of length {} is incompatible with subspace of length {}",
+                vector.inner.len(),
+                self.length()
+            )));
+        }
+        Ok(self.contains(&vector.inner))
+    }
+
+    /// Checks if all vectors of self are in the other subspace.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The subspaces have different lengths.
+    #[pyo3(text_signature = "(self, other)")]
+    pub fn is_subspace_of(&self, other: &Self) -> PyResult<bool> {
+        self.check_same_length(other)?;
+        Ok(self.basis.rows().all(|row| other.contains(&row.to_owned())))
+    }
+
+    /// Returns the smallest subspace containing both self and other.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The subspaces have different lengths.
+    #[pyo3(text_signature = "(self, other)")]
+    pub fn sum(&self, other: &Self) -> PyResult<Self> {
+        self.check_same_length(other)?;
+        Ok(Self::from_generators(
+            &self.basis.vertical_concat_with(&other.basis),
+        ))
+    }
+
+    /// Returns the subspace of vectors in both self and other.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The subspaces have different lengths.
+    #[pyo3(text_signature = "(self, other)")]
+    pub fn intersection(&self, other: &Self) -> PyResult<Self> {
+        self.check_same_length(other)?;
+        let complements = self
+            .orthogonal_complement()
+            .sum(&other.orthogonal_complement())?;
+        Ok(complements.orthogonal_complement())
+    }
+
+    /// Returns the subspace of vectors orthogonal to every vector of self.
+    #[pyo3(text_signature = "(self)")]
+    pub fn orthogonal_complement(&self) -> Self {
+        Self::orthogonal_to(&self.basis)
+    }
+
+    /// Returns representatives of a basis of the quotient of self by other.
+    ///
+    /// The rows of the output are vectors of self that are linearly independent
+    /// modulo other. They generate self together with the intersection
+    /// of self and other.
+    ///
+    /// Example
+    /// -------
+    ///     >>> from qecstruct import BinarySubspace
+    ///     >>> space = BinarySubspace.full(3)
+    ///     >>> space.quotient_basis(BinarySubspace(3, [[0, 1], [1, 2]]))
+    ///     [0]
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The subspaces have different lengths.
+    #[pyo3(text_signature = "(self, other)")]
+    pub fn quotient_basis(&self, other: &Self) -> PyResult<PyBinaryMatrix> {
+        self.check_same_length(other)?;
+        let mut basis = RowBasis::new();
+        for row in other.basis.rows() {
+            basis.insert(row.as_slice());
+        }
+        let representatives = self
+            .basis
+            .rows()
+            .filter(|row| basis.insert(row.as_slice()))
+            .map(|row| row.as_slice().to_vec())
+            .collect();
+        Ok(SparseBinMat::new(self.length(), representatives).into())
+    }
+
+    /// Returns the dimension of the quotient of self by other.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     The subspaces have different lengths.
+    #[pyo3(text_signature = "(self, other)")]
+    pub fn quotient_dimension(&self, other: &Self) -> PyResult<usize> {
+        Ok(self.dimension() - self.intersection(other)?.dimension())
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => serde_pickle::from_slice(s.as_bytes())
+                .map(|basis| {
+                    self.basis = basis;
+                })
+                .map_err(|error| PyValueError::new_err(error.to_string())),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &serde_pickle::to_vec(&self.basis, true).unwrap()).to_object(py))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PyBinarySubspace {
+    fn __repr__(&self) -> String {
+        format!(
+            "BinarySubspace of dimension {} and length {}",
+            self.dimension(),
+            self.length()
+        )
+    }
+
+    fn __richcmp__(&self, other: PyRef<Self>, op: CompareOp) -> PyResult<bool> {
+        match op {
+            CompareOp::Eq => Ok(self.basis == other.basis),
+            CompareOp::Ne => Ok(self.basis != other.basis),
+            _ => Err(PyNotImplementedError::new_err("not implemented")),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[pyproto]
+impl PySequenceProtocol for PyBinarySubspace {
+    fn __contains__(&self, vector: PyRef<PyBinaryVector>) -> PyResult<bool> {
+        self.has_vector(&vector)
+    }
+}
@@ -5,6 +5,7 @@ use pyo3::types::PyBytes;
 use pyo3::ToPyObject;
 use pyo3::{PyIterProtocol, PyNumberProtocol, PyObjectProtocol, PySequenceProtocol};
 use sparse_bin_mat::SparseBinVec;
+use super::dense::{dense_rows, vector_to_dense, vectors_to_dense};
 use super::PyBinaryMatrix;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -21,10 +22,10 @@ use std::hash::{Hash, Hasher};
 ///
 /// Example
 /// -------
-///     >>> from pyqec.sparse import BinaryVector, to_dense
+///     >>> from qecstruct import BinaryVector, to_dense
 ///     >>> vector = BinaryVector(3, [0, 2])
 ///     >>> to_dense(vector)
-///     array([1, 0, 1], dtype=int32)
+///     array([1, 0, 1], dtype=uint8)
 ///
 /// Raises
 /// ------
@@ -48,7 +49,7 @@ impl PyBinaryVector {
     #[args(length = "0", non_trivial_positions = "Vec::new()")]
     fn new(length: usize, non_trivial_positions: Vec<usize>) -> PyResult<Self> {
         SparseBinVec::try_new(length, non_trivial_positions)
-            .map(|vector| Self::from(vector))
+            .map(Self::from)
             .map_err(|error| PyValueError::new_err(error.to_string()))
     }
 
@@ -262,6 +263,54 @@ impl PyBinaryVector {
             .map_err(|error| PyValueError::new_err(error.to_string()))
     }
 
+    /// Returns the vector as a 1D numpy array of uint8.
+    #[pyo3(text_signature = "(self)")]
+    pub fn to_dense(&self, py: Python) -> PyResult<PyObject> {
+        vector_to_dense(py, self.inner.as_view())
+    }
+
+    /// Constructs a vector from a 1D array-like object of 0s and 1s.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the array is not 1D or if an element is not 0 or 1.
+    #[staticmethod]
+    #[pyo3(text_signature = "(array)")]
+    pub fn from_dense(array: &PyAny) -> PyResult<Self> {
+        let (length, mut rows) = dense_rows(array, 1)?;
+        let positions = rows.pop().unwrap_or_default();
+        Ok(Self::from(SparseBinVec::new(length, positions)))
+    }
+
+    /// Returns the vectors as the rows of a 2D numpy array of uint8.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the vectors have different lengths.
+    #[staticmethod]
+    #[pyo3(text_signature = "(vectors)")]
+    pub fn batch_to_dense(py: Python, vectors: Vec<PyRef<Self>>) -> PyResult<PyObject> {
+        vectors_to_dense(py, &vectors)
+    }
+
+    /// Constructs a vector from each row of a 2D array-like object of 0s and 1s.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the array is not 2D or if an element is not 0 or 1.
+    #[staticmethod]
+    #[pyo3(text_signature = "(array)")]
+    pub fn batch_from_dense(array: &PyAny) -> PyResult<Vec<Self>> {
+        let (length, rows) = dense_rows(array, 2)?;
+        Ok(rows
+            .into_iter()
+            .map(|positions| Self::from(SparseBinVec::new(length, positions)))
+            .collect())
+    }
+
     pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
         match state.extract::<&PyBytes>(py) {
             Ok(s) => serde_pickle::from_slice(s.as_bytes())
@@ -292,8 +341,8 @@ impl PyObjectProtocol for PyBinaryVector {
 
     fn __richcmp__(&self, other: PyRef<Self>, op: CompareOp) -> PyResult<bool> {
         match op {
-            CompareOp::Eq => Ok(&self.inner == &other.inner),
-            CompareOp::Ne => Ok(&self.inner != &other.inner),
+            CompareOp::Eq => Ok(self.inner == other.inner),
+            CompareOp::Ne => Ok(self.inner != other.inner),
             _ => Err(PyNotImplementedError::new_err("not implemented")),
         }
     }
@@ -0,0 +1,197 @@
+use crate::pauli::PyPauliOperator;
+use crate::sparse::{PyBinaryMatrix, PyBinaryVector};
+use crate::symplectic::{
+    from_symplectic, is_in_row_space, swap_x_and_z, symplectic_matrix, to_symplectic,
+};
+use pauli::PauliOperator;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::PyObjectProtocol;
+use pyo3::PySequenceProtocol;
+use pyo3::ToPyObject;
+use sparse_bin_mat::SparseBinMat;
+
+/// A general quantum stabilizer code.
+///
+/// The code is defined from a list of commuting Pauli operators
+/// generating its stabilizer group.
+/// Use a `CssCode` instead when all stabilizers contain either
+/// only Xs or only Zs.
+///
+/// Parameters
+/// ----------
+/// stabilizers : Seq[PauliOperator]
+///     The generators of the stabilizer group.
+///     They must all have the same length and commute with each other.
+///
+/// Example
+/// -------
+///     >>> from qecstruct import StabilizerCode, PauliOperator, Pauli
+///     >>> X, Z = Pauli.X(), Pauli.Z()
+///     >>> code = StabilizerCode([
+///     ...     PauliOperator(3, [0, 1], [Z, Z]),
+///     ...     PauliOperator(3, [1, 2], [Z, Z]),
+///     ... ])
+///     >>> code.num_logicals()
+///     1
+///
+/// Raises
+/// ------
+/// ValueError
+///     If the stabilizers have different lengths or don't commute.
+#[pyclass(name = "StabilizerCode", module = "qecstruct")]
+#[pyo3(text_signature = "(stabilizers)")]
+pub struct PyStabilizerCode {
+    // The binary representation (x | z) of the stabilizer generators.
+    pub(crate) stabilizers: SparseBinMat,
+}
+
+impl PyStabilizerCode {
+    pub(crate) fn try_from_operators(stabilizers: &[PauliOperator]) -> PyResult<Self> {
+        let length = stabilizers.first().map(|stab| stab.len()).unwrap_or(0);
+        if stabilizers.iter().any(|stab| stab.len() != length) {
+            return Err(PyValueError::new_err("stabilizers have different lengths"));
+        }
+        let stabilizers = symplectic_matrix(stabilizers, length);
+        if !(&stabilizers * &swap_x_and_z(&stabilizers).transposed()).is_zero() {
+            return Err(PyValueError::new_err("stabilizers don't commute"));
+        }
+        Ok(Self { stabilizers })
+    }
+}
+
+#[pymethods]
+impl PyStabilizerCode {
+    #[new]
+    #[args(stabilizers = "Vec::new()")]
+    pub fn new(stabilizers: Vec<PyPauliOperator>) -> PyResult<Self> {
+        let stabilizers: Vec<PauliOperator> =
+            stabilizers.into_iter().map(|stab| stab.inner).collect();
+        Self::try_from_operators(&stabilizers)
+    }
+
+    /// Returns the stabilizer generators.
+    #[pyo3(text_signature = "(self)")]
+    pub fn stabilizers(&self) -> Vec<PyPauliOperator> {
+        self.stabilizers
+            .rows()
+            .map(|row| from_symplectic(&row).into())
+            .collect()
+    }
+
+    /// Returns the stabilizer generators represented as a binary matrix.
+    ///
+    /// Each row is `(x | z)` where `x` has ones where the stabilizer
+    /// is X or Y and `z` has ones where the stabilizer is Z or Y.
+    #[pyo3(text_signature = "(self)")]
+    pub fn stabs_binary(&self) -> PyBinaryMatrix {
+        self.stabilizers.clone().into()
+    }
+
+    /// The number of qubits in the code.
+    #[pyo3(text_signature = "(self)")]
+    pub fn length(&self) -> usize {
+        self.stabilizers.number_of_columns() / 2
+    }
+
+    /// The number of stabilizer generators in the code.
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_stabs(&self) -> usize {
+        self.stabilizers.number_of_rows()
+    }
+
+    /// The number of encoded qubits.
+    ///
+    /// This is the number of qubits minus the number of
+    /// independent stabilizer generators.
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_logicals(&self) -> usize {
+        self.length() - self.stabilizers.rank()
+    }
+
+    /// The syndrome of a given operator.
+    ///
+    /// Returns
+    /// -------
+    /// BinaryVector
+    ///     The positions of the stabilizer generators
+    ///     anticommuting with the operator.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the operator length is not the same as the code length.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn syndrome_of(&self, operator: &PyPauliOperator) -> PyResult<PyBinaryVector> {
+        self.validate_length(&operator.inner)?;
+        let swapped = swap_x_and_z(&SparseBinMat::new(
+            2 * self.length(),
+            vec![to_symplectic(&operator.inner).to_positions_vec()],
+        ));
+        let syndrome = &self.stabilizers * &swapped.transposed();
+        Ok(syndrome.transposed().row(0).unwrap().to_owned().into())
+    }
+
+    /// Checks if the operator is in the stabilizer group up to a phase.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn has_stabilizer(&self, operator: &PyPauliOperator) -> PyResult<bool> {
+        self.validate_length(&operator.inner)?;
+        Ok(is_in_row_space(
+            &self.stabilizers,
+            &to_symplectic(&operator.inner),
+        ))
+    }
+
+    /// Checks if the operator commutes with all stabilizers
+    /// without being a stabilizer.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn has_logical(&self, operator: &PyPauliOperator) -> PyResult<bool> {
+        Ok(self.syndrome_of(operator)?.is_zero() && !self.has_stabilizer(operator)?)
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => serde_pickle::from_slice(s.as_bytes())
+                .map(|stabilizers| {
+                    self.stabilizers = stabilizers;
+                })
+                .map_err(|error| PyValueError::new_err(error.to_string())),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(py, &serde_pickle::to_vec(&self.stabilizers, true).unwrap()).to_object(py))
+    }
+}
+
+impl PyStabilizerCode {
+    fn validate_length(&self, operator: &PauliOperator) -> PyResult<()> {
+        if operator.len() != self.length() {
+            return Err(PyValueError::new_err(format!(
+                "operator of length {} for code of length {}",
+                operator.len(),
+                self.length()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PyStabilizerCode {
+    fn __repr__(&self) -> String {
+        self.stabilizers
+            .rows()
+            .map(|row| format!("{}\n", from_symplectic(&row)))
+            .collect()
+    }
+}
+
+#[pyproto]
+impl PySequenceProtocol for PyStabilizerCode {
+    fn __len__(&self) -> usize {
+        self.length()
+    }
+}
@@ -0,0 +1,337 @@
+use crate::pauli::PyPauliOperator;
+use crate::sparse::{PyBinaryMatrix, PyBinaryVector};
+use crate::symplectic::{
+    centralizer, from_symplectic, swap_x_and_z, symplectic_matrix, to_symplectic, RowBasis,
+};
+use pauli::PauliOperator;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3::PyObjectProtocol;
+use pyo3::PySequenceProtocol;
+use pyo3::ToPyObject;
+use sparse_bin_mat::{SparseBinMat, SparseBinVec};
+
+/// A quantum subsystem code defined from the generators of its gauge group.
+///
+/// The gauge generators don't need to commute.
+/// The stabilizer group is the center of the gauge group, that is
+/// the gauge operators commuting with every gauge operator.
+/// The bare logical operators commute with the whole gauge group
+/// and act trivially on the gauge qubits while the dressed logical
+/// operators only commute with the stabilizers.
+///
+/// Parameters
+/// ----------
+/// gauge_generators : Seq[PauliOperator]
+///     The generators of the gauge group.
+///     They must all have the same length.
+///
+/// Example
+/// -------
+///     >>> from qecstruct import SubsystemCode, PauliOperator, Pauli
+///     >>> X, Z = Pauli.X(), Pauli.Z()
+///     >>> code = SubsystemCode([
+///     ...     PauliOperator(3, [0, 1], [X, X]),
+///     ...     PauliOperator(3, [1, 2], [Z, Z]),
+///     ... ])
+///     >>> code.num_stabs(), code.num_gauge_qubits(), code.num_logicals()
+///     (0, 1, 2)
+///
+/// Raises
+/// ------
+/// ValueError
+///     If the gauge generators have different lengths.
+#[pyclass(name = "SubsystemCode", module = "qecstruct")]
+#[pyo3(text_signature = "(gauge_generators)")]
+pub struct PySubsystemCode {
+    // All operators are stored in their binary representation (x | z).
+    gauge_generators: SparseBinMat,
+    stabilizers: SparseBinMat,
+    bare_logicals: SparseBinMat,
+    dressed_logicals: SparseBinMat,
+}
+
+impl PySubsystemCode {
+    pub(crate) fn try_from_operators(gauge_generators: &[PauliOperator]) -> PyResult<Self> {
+        let length = gauge_generators
+            .first()
+            .map(|gauge| gauge.len())
+            .unwrap_or(0);
+        if gauge_generators.iter().any(|gauge| gauge.len() != length) {
+            return Err(PyValueError::new_err(
+                "gauge generators have different lengths",
+            ));
+        }
+        Ok(Self::from_gauge_matrix(symplectic_matrix(
+            gauge_generators,
+            length,
+        )))
+    }
+
+    fn from_gauge_matrix(gauge_generators: SparseBinMat) -> Self {
+        let stabilizers = center(&gauge_generators);
+        let bare_logicals = quotient(&centralizer(&gauge_generators), &stabilizers);
+        let dressed_logicals = quotient(&centralizer(&stabilizers), &gauge_generators);
+        Self {
+            gauge_generators,
+            stabilizers,
+            bare_logicals,
+            dressed_logicals,
+        }
+    }
+
+    fn validate_length(&self, operator: &PauliOperator) -> PyResult<()> {
+        if operator.len() != self.length() {
+            return Err(PyValueError::new_err(format!(
+                "operator of length {} for code of length {}",
+                operator.len(),
+                self.length()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl PySubsystemCode {
+    #[new]
+    #[args(gauge_generators = "Vec::new()")]
+    pub fn new(gauge_generators: Vec<PyPauliOperator>) -> PyResult<Self> {
+        let gauge_generators: Vec<PauliOperator> = gauge_generators
+            .into_iter()
+            .map(|gauge| gauge.inner)
+            .collect();
+        Self::try_from_operators(&gauge_generators)
+    }
+
+    /// Returns the gauge generators.
+    #[pyo3(text_signature = "(self)")]
+    pub fn gauge_generators(&self) -> Vec<PyPauliOperator> {
+        operators(&self.gauge_generators)
+    }
+
+    /// Returns a set of independent generators of the stabilizer group.
+    #[pyo3(text_signature = "(self)")]
+    pub fn stabilizers(&self) -> Vec<PyPauliOperator> {
+        operators(&self.stabilizers)
+    }
+
+    /// Returns the generators of the bare logical operators
+    /// modulo the stabilizers.
+    ///
+    /// There are twice as many generators as logical qubits.
+    #[pyo3(text_signature = "(self)")]
+    pub fn bare_logicals(&self) -> Vec<PyPauliOperator> {
+        operators(&self.bare_logicals)
+    }
+
+    /// Returns the generators of the dressed logical operators
+    /// modulo the gauge group.
+    ///
+    /// There are twice as many generators as logical qubits.
+    #[pyo3(text_signature = "(self)")]
+    pub fn dressed_logicals(&self) -> Vec<PyPauliOperator> {
+        operators(&self.dressed_logicals)
+    }
+
+    /// Returns the gauge generators represented as a binary matrix.
+    ///
+    /// Each row is `(x | z)` where `x` has ones where the operator
+    /// is X or Y and `z` has ones where the operator is Z or Y.
+    #[pyo3(text_signature = "(self)")]
+    pub fn gauge_binary(&self) -> PyBinaryMatrix {
+        self.gauge_generators.clone().into()
+    }
+
+    /// Returns the stabilizer generators represented as a binary matrix.
+    #[pyo3(text_signature = "(self)")]
+    pub fn stabs_binary(&self) -> PyBinaryMatrix {
+        self.stabilizers.clone().into()
+    }
+
+    /// The number of qubits in the code.
+    #[pyo3(text_signature = "(self)")]
+    pub fn length(&self) -> usize {
+        self.gauge_generators.number_of_columns() / 2
+    }
+
+    /// The number of independent stabilizer generators.
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_stabs(&self) -> usize {
+        self.stabilizers.number_of_rows()
+    }
+
+    /// The number of gauge qubits.
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_gauge_qubits(&self) -> usize {
+        (self.gauge_generators.rank() - self.num_stabs()) / 2
+    }
+
+    /// The number of logical qubits.
+    #[pyo3(text_signature = "(self)")]
+    pub fn num_logicals(&self) -> usize {
+        self.bare_logicals.number_of_rows() / 2
+    }
+
+    /// The syndrome of a given operator with respect to the stabilizers.
+    ///
+    /// Returns
+    /// -------
+    /// BinaryVector
+    ///     The positions of the stabilizer generators, in the order
+    ///     given by `stabilizers`, anticommuting with the operator.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the operator length is not the same as the code length.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn syndrome_of(&self, operator: &PyPauliOperator) -> PyResult<PyBinaryVector> {
+        self.validate_length(&operator.inner)?;
+        Ok(anticommuting_rows(&self.stabilizers, &operator.inner).into())
+    }
+
+    /// The outcomes of measuring the gauge generators on a given error.
+    ///
+    /// Returns
+    /// -------
+    /// BinaryVector
+    ///     The positions of the gauge generators anticommuting
+    ///     with the operator.
+    ///
+    /// Raises
+    /// ------
+    /// ValueError
+    ///     If the operator length is not the same as the code length.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn gauge_syndrome_of(&self, operator: &PyPauliOperator) -> PyResult<PyBinaryVector> {
+        self.validate_length(&operator.inner)?;
+        Ok(anticommuting_rows(&self.gauge_generators, &operator.inner).into())
+    }
+
+    /// Checks if the operator is in the stabilizer group up to a phase.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn has_stabilizer(&self, operator: &PyPauliOperator) -> PyResult<bool> {
+        self.validate_length(&operator.inner)?;
+        Ok(spans(&self.stabilizers, &operator.inner))
+    }
+
+    /// Checks if the operator is in the gauge group up to a phase.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn has_gauge(&self, operator: &PyPauliOperator) -> PyResult<bool> {
+        self.validate_length(&operator.inner)?;
+        Ok(spans(&self.gauge_generators, &operator.inner))
+    }
+
+    /// Checks if the operator commutes with the gauge group
+    /// without being a stabilizer.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn has_bare_logical(&self, operator: &PyPauliOperator) -> PyResult<bool> {
+        Ok(self.gauge_syndrome_of(operator)?.is_zero() && !self.has_stabilizer(operator)?)
+    }
+
+    /// Checks if the operator commutes with the stabilizers
+    /// without being in the gauge group.
+    #[pyo3(text_signature = "(self, operator)")]
+    pub fn has_dressed_logical(&self, operator: &PyPauliOperator) -> PyResult<bool> {
+        Ok(self.syndrome_of(operator)?.is_zero() && !self.has_gauge(operator)?)
+    }
+
+    pub fn __setstate__(&mut self, py: Python, state: PyObject) -> PyResult<()> {
+        match state.extract::<&PyBytes>(py) {
+            Ok(s) => serde_pickle::from_slice(s.as_bytes())
+                .map(|gauge_generators| {
+                    *self = Self::from_gauge_matrix(gauge_generators);
+                })
+                .map_err(|error| PyValueError::new_err(error.to_string())),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn __getstate__(&self, py: Python) -> PyResult<PyObject> {
+        Ok(PyBytes::new(
+            py,
+            &serde_pickle::to_vec(&self.gauge_generators, true).unwrap(),
+        )
+        .to_object(py))
+    }
+}
+
+#[pyproto]
+impl PyObjectProtocol for PySubsystemCode {
+    fn __repr__(&self) -> String {
+        self.gauge_generators
+            .rows()
+            .map(|row| format!("{}\n", from_symplectic(&row)))
+            .collect()
+    }
+}
+
+#[pyproto]
+impl PySequenceProtocol for PySubsystemCode {
+    fn __len__(&self) -> usize {
+        self.length()
+    }
+}
+
+// The center is generated by the combinations of gauge generators
+// commuting with every gauge generator. Since the commutation matrix
+// is symmetric, these combinations are given by its nullspace.
+fn center(gauge_generators: &SparseBinMat) -> SparseBinMat {
+    let num_columns = gauge_generators.number_of_columns();
+    if gauge_generators.number_of_rows() == 0 {
+        return SparseBinMat::zeros(0, num_columns);
+    }
+    let commutations = gauge_generators * &swap_x_and_z(gauge_generators).transposed();
+    let combinations = if commutations.is_zero() {
+        SparseBinMat::identity(gauge_generators.number_of_rows())
+    } else {
+        commutations.nullspace()
+    };
+    if combinations.number_of_rows() == 0 {
+        return SparseBinMat::zeros(0, num_columns);
+    }
+    quotient(
+        &(&combinations * gauge_generators),
+        &SparseBinMat::zeros(0, num_columns),
+    )
+}
+
+// Returns the rows of the matrix that are independent from each other
+// and from the rows of the subgroup.
+fn quotient(matrix: &SparseBinMat, subgroup: &SparseBinMat) -> SparseBinMat {
+    let mut basis = RowBasis::new();
+    for row in subgroup.rows() {
+        basis.insert(row.as_slice());
+    }
+    let rows = matrix
+        .rows()
+        .filter(|row| basis.insert(row.as_slice()))
+        .map(|row| row.as_slice().to_vec())
+        .collect();
+    SparseBinMat::new(matrix.number_of_columns(), rows)
+}
+
+fn spans(matrix: &SparseBinMat, operator: &PauliOperator) -> bool {
+    let mut basis = RowBasis::new();
+    for row in matrix.rows() {
+        basis.insert(row.as_slice());
+    }
+    basis.spans(to_symplectic(operator).as_slice())
+}
+
+fn anticommuting_rows(matrix: &SparseBinMat, operator: &PauliOperator) -> SparseBinVec {
+    let swapped = swap_x_and_z(&SparseBinMat::new(
+        matrix.number_of_columns(),
+        vec![to_symplectic(operator).to_positions_vec()],
+    ));
+    matrix * &swapped.row(0).unwrap()
+}
+
+fn operators(matrix: &SparseBinMat) -> Vec<PyPauliOperator> {
+    matrix
+        .rows()
+        .map(|row| from_symplectic(&row).into())
+        .collect()
+}
@@ -0,0 +1,164 @@
+use pauli::{PauliOperator, X, Y, Z};
+use sparse_bin_mat::{SparseBinMat, SparseBinVec, SparseBinVecBase};
+use std::collections::BTreeMap;
+use std::ops::Deref;
+
+/// Returns the binary representation `(x | z)` of the operator.
+///
+/// The first half of the vector has ones where the operator is X or Y
+/// and the second half has ones where the operator is Z or Y.
+pub(crate) fn to_symplectic(operator: &PauliOperator) -> SparseBinVec {
+    let length = operator.len();
+    let mut x_positions = Vec::new();
+    let mut z_positions = Vec::new();
+    for (position, pauli) in operator.iter() {
+        if *pauli == X || *pauli == Y {
+            x_positions.push(position);
+        }
+        if *pauli == Z || *pauli == Y {
+            z_positions.push(position + length);
+        }
+    }
+    x_positions.append(&mut z_positions);
+    SparseBinVec::new(2 * length, x_positions)
+}
+
+/// Returns the operator represented by the binary vector `(x | z)`.
+pub(crate) fn from_symplectic<T: Deref<Target = [usize]>>(
+    vector: &SparseBinVecBase<T>,
+) -> PauliOperator {
+    let length = vector.len() / 2;
+    let mut paulis = vec![None; length];
+    for position in vector.non_trivial_positions() {
+        if position < length {
+            paulis[position] = Some(X);
+        } else {
+            paulis[position - length] = match paulis[position - length] {
+                Some(_) => Some(Y),
+                None => Some(Z),
+            };
+        }
+    }
+    let (positions, paulis) = paulis
+        .into_iter()
+        .enumerate()
+        .filter_map(|(position, pauli)| pauli.map(|pauli| (position, pauli)))
+        .unzip();
+    PauliOperator::new(length, positions, paulis)
+}
+
+/// Returns the matrix where each row is the binary representation
+/// of the corresponding operator.
+///
+/// This assumes that all operators have the given length.
+pub(crate) fn symplectic_matrix(operators: &[PauliOperator], length: usize) -> SparseBinMat {
+    let rows = operators
+        .iter()
+        .map(|operator| to_symplectic(operator).to_positions_vec())
+        .collect();
+    SparseBinMat::new(2 * length, rows)
+}
+
+/// Swaps the X and Z halves of each row of a symplectic matrix.
+///
+/// The dot product of a symplectic vector with a swapped vector
+/// is 1 if the corresponding operators anticommute and 0 otherwise.
+pub(crate) fn swap_x_and_z(matrix: &SparseBinMat) -> SparseBinMat {
+    let length = matrix.number_of_columns() / 2;
+    let rows = matrix
+        .rows()
+        .map(|row| {
+            let mut swapped: Vec<usize> = row
+                .non_trivial_positions()
+                .map(|position| (position + length) % (2 * length))
+                .collect();
+            swapped.sort_unstable();
+            swapped
+        })
+        .collect();
+    SparseBinMat::new(matrix.number_of_columns(), rows)
+}
+
+/// Checks if the vector is a linear combination of the rows of the matrix.
+pub(crate) fn is_in_row_space<T: Deref<Target = [usize]>>(
+    matrix: &SparseBinMat,
+    vector: &SparseBinVecBase<T>,
+) -> bool {
+    let vector = SparseBinMat::new(vector.len(), vec![vector.as_slice().to_vec()]);
+    matrix.vertical_concat_with(&vector).rank() == matrix.rank()
+}
+
+/// Returns the binary representation of the operators commuting
+/// with all the operators represented by the rows of the matrix.
+pub(crate) fn centralizer(matrix: &SparseBinMat) -> SparseBinMat {
+    if matrix.number_of_rows() == 0 {
+        return SparseBinMat::identity(matrix.number_of_columns());
+    }
+    swap_x_and_z(&matrix.nullspace())
+}
+
+/// An incrementally built basis of the span of some binary vectors.
+///
+/// The basis vectors are kept in echelon form such that
+/// checking if a vector is in the span is linear in the size of the basis.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RowBasis {
+    // The basis vectors indexed by their first non trivial position.
+    rows: BTreeMap<usize, Vec<usize>>,
+}
+
+impl RowBasis {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the positions of a vector to the basis
+    /// and returns true if it was not already in the span.
+    pub(crate) fn insert(&mut self, positions: &[usize]) -> bool {
+        let reduced = self.reduce(positions);
+        match reduced.first() {
+            Some(pivot) => {
+                self.rows.insert(*pivot, reduced);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Checks if a vector is in the span of the basis.
+    pub(crate) fn spans(&self, positions: &[usize]) -> bool {
+        self.reduce(positions).is_empty()
+    }
+
+    // Adding a basis vector only changes positions after its pivot,
+    // so the pivots can be eliminated in increasing order.
+    fn reduce(&self, positions: &[usize]) -> Vec<usize> {
+        let mut reduced = positions.to_vec();
+        for (pivot, row) in self.rows.iter() {
+            if reduced.binary_search(pivot).is_ok() {
+                reduced = symmetric_difference(&reduced, row);
+            }
+        }
+        reduced
+    }
+}
+
+// Returns the sorted elements in exactly one of the sorted slices.
+fn symmetric_difference(first: &[usize], second: &[usize]) -> Vec<usize> {
+    let mut difference = Vec::with_capacity(first.len() + second.len());
+    let mut first = first.iter().peekable();
+    let mut second = second.iter().peekable();
+    loop {
+        match (first.peek(), second.peek()) {
+            (Some(a), Some(b)) if a < b => difference.push(*first.next().unwrap()),
+            (Some(a), Some(b)) if a > b => difference.push(*second.next().unwrap()),
+            (Some(_), Some(_)) => {
+                first.next();
+                second.next();
+            }
+            (Some(_), None) => difference.push(*first.next().unwrap()),
+            (None, Some(_)) => difference.push(*second.next().unwrap()),
+            (None, None) => return difference,
+        }
+    }
+}
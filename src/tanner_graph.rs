@@ -0,0 +1,346 @@
+use sparse_bin_mat::SparseBinMat;
+use std::collections::{BTreeSet, VecDeque};
+
+/// The bipartite graph connecting the bits and the checks of a code.
+///
+/// There is an edge between a bit and a check if the corresponding
+/// element of the parity check matrix has value 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TannerGraph {
+    bit_neighbors: Vec<Vec<usize>>,
+    check_neighbors: Vec<Vec<usize>>,
+}
+
+impl TannerGraph {
+    /// Creates a graph with the given number of bits and checks, but without edges.
+    pub(crate) fn without_edges(num_bits: usize, num_checks: usize) -> Self {
+        Self {
+            bit_neighbors: vec![Vec::new(); num_bits],
+            check_neighbors: vec![Vec::new(); num_checks],
+        }
+    }
+
+    /// Creates the graph associated with the given parity check matrix.
+    pub(crate) fn from_parity_check_matrix(matrix: &SparseBinMat) -> Self {
+        let mut graph = Self::without_edges(matrix.number_of_columns(), matrix.number_of_rows());
+        for (check, row) in matrix.rows().enumerate() {
+            for bit in row.non_trivial_positions() {
+                graph.add_edge(bit, check);
+            }
+        }
+        graph
+    }
+
+    pub(crate) fn num_bits(&self) -> usize {
+        self.bit_neighbors.len()
+    }
+
+    pub(crate) fn num_checks(&self) -> usize {
+        self.check_neighbors.len()
+    }
+
+    pub(crate) fn checks_adjacent_to_bit(&self, bit: usize) -> &[usize] {
+        &self.bit_neighbors[bit]
+    }
+
+    pub(crate) fn bits_adjacent_to_check(&self, check: usize) -> &[usize] {
+        &self.check_neighbors[check]
+    }
+
+    pub(crate) fn check_degree(&self, check: usize) -> usize {
+        self.check_neighbors[check].len()
+    }
+
+    /// Returns the number of bits of each degree.
+    ///
+    /// The element at index `d` is the number of bits of degree `d`.
+    pub(crate) fn bit_degree_distribution(&self) -> Vec<usize> {
+        degree_distribution(&self.bit_neighbors)
+    }
+
+    /// Returns the number of checks of each degree.
+    ///
+    /// The element at index `d` is the number of checks of degree `d`.
+    pub(crate) fn check_degree_distribution(&self) -> Vec<usize> {
+        degree_distribution(&self.check_neighbors)
+    }
+
+    pub(crate) fn has_edge(&self, bit: usize, check: usize) -> bool {
+        self.bit_neighbors[bit].contains(&check)
+    }
+
+    /// Adds an edge between the given bit and check.
+    ///
+    /// This assumes that the edge is not already in the graph.
+    pub(crate) fn add_edge(&mut self, bit: usize, check: usize) {
+        self.bit_neighbors[bit].push(check);
+        self.check_neighbors[check].push(bit);
+    }
+
+    /// Removes the edge between the given bit and check if it is in the graph.
+    pub(crate) fn remove_edge(&mut self, bit: usize, check: usize) {
+        self.bit_neighbors[bit].retain(|neighbor| *neighbor != check);
+        self.check_neighbors[check].retain(|neighbor| *neighbor != bit);
+    }
+
+    /// Checks if adding an edge between the given bit and check
+    /// would close a cycle of length 4.
+    pub(crate) fn would_create_four_cycle(&self, bit: usize, check: usize) -> bool {
+        self.checks_adjacent_to_bit(bit)
+            .iter()
+            .filter(|other_check| **other_check != check)
+            .flat_map(|other_check| self.bits_adjacent_to_check(*other_check))
+            .any(|other_bit| *other_bit != bit && self.has_edge(*other_bit, check))
+    }
+
+    /// Returns the distance, in number of edges, between the given bit
+    /// and every check or None for the checks that are not reachable.
+    pub(crate) fn check_distances_from_bit(&self, bit: usize) -> Vec<Option<usize>> {
+        let mut check_distances = vec![None; self.num_checks()];
+        let mut visited_bits = vec![false; self.num_bits()];
+        visited_bits[bit] = true;
+        let mut queue = VecDeque::new();
+        queue.push_back((bit, 0));
+        while let Some((bit, distance)) = queue.pop_front() {
+            for &check in self.checks_adjacent_to_bit(bit) {
+                if check_distances[check].is_none() {
+                    check_distances[check] = Some(distance + 1);
+                    for &next_bit in self.bits_adjacent_to_check(check) {
+                        if !visited_bits[next_bit] {
+                            visited_bits[next_bit] = true;
+                            queue.push_back((next_bit, distance + 2));
+                        }
+                    }
+                }
+            }
+        }
+        check_distances
+    }
+
+    /// Returns the parity check matrix where each row corresponds to a check.
+    pub(crate) fn to_parity_check_matrix(&self) -> SparseBinMat {
+        let rows = self
+            .check_neighbors
+            .iter()
+            .map(|bits| {
+                let mut row = bits.clone();
+                row.sort_unstable();
+                row
+            })
+            .collect();
+        SparseBinMat::new(self.num_bits(), rows)
+    }
+
+    /// Returns the length of the shortest cycle or None if the graph is acyclic.
+    pub(crate) fn girth(&self) -> Option<usize> {
+        let num_nodes = self.num_bits() + self.num_checks();
+        let mut girth: Option<usize> = None;
+        for source in 0..self.num_bits() {
+            let mut distances = vec![None; num_nodes];
+            let mut parents = vec![None; num_nodes];
+            distances[source] = Some(0);
+            let mut queue = VecDeque::new();
+            queue.push_back(source);
+            while let Some(node) = queue.pop_front() {
+                let distance = distances[node].unwrap_or(0);
+                if girth.is_some_and(|girth| 2 * distance >= girth) {
+                    break;
+                }
+                for neighbor in self.node_neighbors(node) {
+                    match distances[neighbor] {
+                        None => {
+                            distances[neighbor] = Some(distance + 1);
+                            parents[neighbor] = Some(node);
+                            queue.push_back(neighbor);
+                        }
+                        Some(other_distance) if parents[node] != Some(neighbor) => {
+                            let length = distance + other_distance + 1;
+                            girth = Some(girth.map_or(length, |girth| girth.min(length)));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        girth
+    }
+
+    // Nodes are indexed with the bits first followed by the checks.
+    fn node_neighbors(&self, node: usize) -> Vec<usize> {
+        if node < self.num_bits() {
+            self.checks_adjacent_to_bit(node)
+                .iter()
+                .map(|check| check + self.num_bits())
+                .collect()
+        } else {
+            self.bits_adjacent_to_check(node - self.num_bits()).to_vec()
+        }
+    }
+
+    /// Returns the number of cycles of the given length.
+    ///
+    /// The length must be even since the graph is bipartite.
+    pub(crate) fn num_cycles(&self, length: usize) -> usize {
+        if length < 4 || length % 2 == 1 {
+            return 0;
+        }
+        let mut path = CyclePath {
+            anchor: 0,
+            num_checks: length / 2,
+            bits: Vec::new(),
+            checks: Vec::new(),
+        };
+        let mut count = 0;
+        for anchor in 0..self.num_bits() {
+            path.anchor = anchor;
+            count += self.count_cycles_from(anchor, &mut path);
+        }
+        // Each cycle is visited once in each direction.
+        count / 2
+    }
+
+    // Counts the cycles closing on the anchor of the path where the anchor
+    // is the smallest bit of the cycle.
+    fn count_cycles_from(&self, bit: usize, path: &mut CyclePath) -> usize {
+        let mut count = 0;
+        for &check in self.checks_adjacent_to_bit(bit) {
+            if path.checks.contains(&check) {
+                continue;
+            }
+            if path.checks.len() + 1 == path.num_checks {
+                if !path.checks.is_empty() && self.has_edge(path.anchor, check) {
+                    count += 1;
+                }
+                continue;
+            }
+            path.checks.push(check);
+            for &next_bit in self.bits_adjacent_to_check(check) {
+                if next_bit > path.anchor && !path.bits.contains(&next_bit) {
+                    path.bits.push(next_bit);
+                    count += self.count_cycles_from(next_bit, path);
+                    path.bits.pop();
+                }
+            }
+            path.checks.pop();
+        }
+        count
+    }
+
+    /// Returns all connected sets of at most max_size bits
+    /// such that no check is connected to exactly one bit of the set.
+    pub(crate) fn stopping_sets(&self, max_size: usize) -> Vec<Vec<usize>> {
+        self.connected_bit_sets(max_size)
+            .into_iter()
+            .filter(|bits| {
+                self.check_degrees_in(bits)
+                    .into_iter()
+                    .all(|(_, degree)| degree != 1)
+            })
+            .collect()
+    }
+
+    /// Returns all connected sets of at most max_size bits
+    /// inducing at most max_unsatisfied checks of odd degree
+    /// together with the number of such checks.
+    pub(crate) fn trapping_sets(
+        &self,
+        max_size: usize,
+        max_unsatisfied: usize,
+    ) -> Vec<(Vec<usize>, usize)> {
+        self.connected_bit_sets(max_size)
+            .into_iter()
+            .filter_map(|bits| {
+                let unsatisfied = self
+                    .check_degrees_in(&bits)
+                    .into_iter()
+                    .filter(|(_, degree)| degree % 2 == 1)
+                    .count();
+                if unsatisfied <= max_unsatisfied {
+                    Some((bits, unsatisfied))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    // Returns the checks connected to the bits together with
+    // the number of bits of the set they are connected to.
+    fn check_degrees_in(&self, bits: &[usize]) -> Vec<(usize, usize)> {
+        let mut checks: Vec<usize> = bits
+            .iter()
+            .flat_map(|bit| self.checks_adjacent_to_bit(*bit))
+            .cloned()
+            .collect();
+        checks.sort_unstable();
+        let mut degrees: Vec<(usize, usize)> = Vec::new();
+        for check in checks {
+            match degrees.last_mut() {
+                Some((last, degree)) if *last == check => *degree += 1,
+                _ => degrees.push((check, 1)),
+            }
+        }
+        degrees
+    }
+
+    // Returns the sets of at most max_size bits that are connected in the graph
+    // sorted by size and then lexicographically.
+    fn connected_bit_sets(&self, max_size: usize) -> Vec<Vec<usize>> {
+        let mut sets = BTreeSet::new();
+        if max_size > 0 {
+            for bit in 0..self.num_bits() {
+                self.extend_connected_set(vec![bit], max_size, &mut sets);
+            }
+        }
+        let mut sets: Vec<Vec<usize>> = sets.into_iter().collect();
+        sets.sort_by_key(|set| set.len());
+        sets
+    }
+
+    // Adds the set and all its connected extensions with larger bits than its
+    // smallest bit. Sets that were already found are not explored again.
+    fn extend_connected_set(
+        &self,
+        set: Vec<usize>,
+        max_size: usize,
+        sets: &mut BTreeSet<Vec<usize>>,
+    ) {
+        if sets.contains(&set) {
+            return;
+        }
+        sets.insert(set.clone());
+        if set.len() >= max_size {
+            return;
+        }
+        let anchor = set[0];
+        let candidates: BTreeSet<usize> = set
+            .iter()
+            .flat_map(|bit| self.checks_adjacent_to_bit(*bit))
+            .flat_map(|check| self.bits_adjacent_to_check(*check))
+            .filter(|bit| **bit > anchor && !set.contains(bit))
+            .cloned()
+            .collect();
+        for candidate in candidates {
+            let mut extended = set.clone();
+            let position = extended.binary_search(&candidate).unwrap_err();
+            extended.insert(position, candidate);
+            self.extend_connected_set(extended, max_size, sets);
+        }
+    }
+}
+
+struct CyclePath {
+    anchor: usize,
+    num_checks: usize,
+    bits: Vec<usize>,
+    checks: Vec<usize>,
+}
+
+fn degree_distribution(neighbors: &[Vec<usize>]) -> Vec<usize> {
+    let max_degree = neighbors.iter().map(|n| n.len()).max().unwrap_or(0);
+    let mut distribution = vec![0; max_degree + 1];
+    for node_neighbors in neighbors {
+        distribution[node_neighbors.len()] += 1;
+    }
+    distribution
+}
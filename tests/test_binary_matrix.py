@@ -0,0 +1,177 @@
+from qecstruct import BinaryMatrix, BinaryVector
+import pytest
+
+
+def test_circulant():
+    matrix = BinaryMatrix.circulant(4, [0, 1])
+
+    assert matrix == BinaryMatrix(4, [[0, 1], [1, 2], [2, 3], [0, 3]])
+
+
+def test_circulant_with_out_of_bound_position():
+    with pytest.raises(ValueError):
+        BinaryMatrix.circulant(4, [0, 4])
+
+
+def test_tanner_graph_analysis():
+    matrix = BinaryMatrix(4, [[0, 1, 2], [1, 2, 3], [0, 3]])
+
+    assert matrix.girth() == 4
+    assert matrix.num_cycles(4) == 1
+    assert matrix.num_cycles(6) == 2
+    assert matrix.column_degree_distribution() == [0, 0, 4]
+    assert matrix.row_degree_distribution() == [0, 0, 1, 2]
+    assert matrix.stopping_sets(2) == [[1, 2]]
+
+
+def test_indexing():
+    matrix = BinaryMatrix(4, [[0, 1], [1, 2], [2, 3]])
+
+    assert matrix[0, 1] == 1
+    assert matrix[-1, 0] == 0
+    assert matrix[1] == BinaryVector(4, [1, 2])
+    assert matrix[1, 1:] == BinaryVector(3, [0, 1])
+    assert matrix[:, 2] == BinaryVector(3, [1, 2])
+    assert matrix[[2, 0], ::2] == BinaryMatrix(2, [[1], [0]])
+    assert matrix[:2, [3, 1, 1]] == BinaryMatrix(3, [[1, 2], [1, 2]])
+    assert len(matrix) == 3
+
+
+def test_indexing_out_of_bound():
+    matrix = BinaryMatrix(4, [[0, 1], [1, 2], [2, 3]])
+
+    with pytest.raises(IndexError):
+        matrix[3, 0]
+    with pytest.raises(IndexError):
+        matrix[:, [0, 4]]
+    with pytest.raises(TypeError):
+        matrix["a"]
+
+
+def test_submatrices():
+    matrix = BinaryMatrix(4, [[0, 1], [1, 2], [2, 3]])
+
+    assert matrix.keep_only_rows([0, 2]) == BinaryMatrix(4, [[0, 1], [2, 3]])
+    assert matrix.keep_only_columns([1, 3]) == BinaryMatrix(2, [[0], [0], [1]])
+    assert matrix.without_rows([1]) == BinaryMatrix(4, [[0, 1], [2, 3]])
+    assert matrix.without_columns([0, 2]) == BinaryMatrix(2, [[0], [0], [1]])
+    assert matrix.column(1) == BinaryVector(3, [0, 1])
+    with pytest.raises(ValueError):
+        matrix.keep_only_rows([2, 0])
+    with pytest.raises(ValueError):
+        matrix.without_columns([4])
+
+
+def test_permute_columns():
+    matrix = BinaryMatrix(3, [[0, 1], [2]])
+
+    assert matrix.permute_columns([2, 0, 1]) == BinaryMatrix(3, [[1, 2], [0]])
+    with pytest.raises(ValueError):
+        matrix.permute_columns([0, 0, 1])
+
+
+def test_in_place_editing():
+    matrix = BinaryMatrix(3, [[0, 1], [2]])
+
+    matrix.swap_rows(0, 1)
+    assert matrix == BinaryMatrix(3, [[2], [0, 1]])
+
+    matrix.set(0, 0)
+    matrix.clear(1, 1)
+    matrix.flip(1, 2)
+    assert matrix == BinaryMatrix(3, [[0, 2], [0, 2]])
+
+    matrix[0, 2] = 0
+    matrix[-1, -1] = 0
+    assert matrix == BinaryMatrix(3, [[0], [0]])
+
+    with pytest.raises(IndexError):
+        matrix.flip(2, 0)
+    with pytest.raises(IndexError):
+        matrix.swap_rows(0, 2)
+    with pytest.raises(ValueError):
+        matrix[0, 0] = 2
+
+
+def test_kron():
+    matrix = BinaryMatrix(2, [[1], [0]])
+    other = BinaryMatrix(3, [[0, 1], [1, 2]])
+
+    expected = BinaryMatrix(6, [[3, 4], [4, 5], [0, 1], [1, 2]])
+    assert matrix.kron(other) == expected
+    assert BinaryMatrix.identity(2).kron(other).shape() == (4, 6)
+
+
+def test_direct_sum():
+    matrix = BinaryMatrix(2, [[0, 1]])
+
+    assert matrix.direct_sum(BinaryMatrix.identity(2)) == BinaryMatrix(
+        4, [[0, 1], [2], [3]]
+    )
+
+
+def test_block():
+    a = BinaryMatrix(2, [[0, 1]])
+    blocks = [
+        [a, BinaryMatrix.zeros(1, 1)],
+        [BinaryMatrix.identity(2), BinaryMatrix(1, [[0], []])],
+    ]
+
+    assert BinaryMatrix.block(blocks) == BinaryMatrix(3, [[0, 1], [0, 2], [1]])
+
+
+def test_block_with_incompatible_shapes():
+    with pytest.raises(ValueError):
+        BinaryMatrix.block([[BinaryMatrix.identity(2), BinaryMatrix.identity(3)]])
+    with pytest.raises(ValueError):
+        BinaryMatrix.block([[BinaryMatrix.identity(2)], [BinaryMatrix.zeros(2, 3)]])
+
+
+def test_hypergraph_product_from_blocks():
+    h = BinaryMatrix(3, [[0, 1], [1, 2]])
+    hx = BinaryMatrix.block(
+        [
+            [
+                h.kron(BinaryMatrix.identity(3)),
+                BinaryMatrix.identity(2).kron(h.transposed()),
+            ]
+        ]
+    )
+    hz = BinaryMatrix.block(
+        [
+            [
+                BinaryMatrix.identity(3).kron(h),
+                h.transposed().kron(BinaryMatrix.identity(2)),
+            ]
+        ]
+    )
+
+    assert hx.dot_with_matrix(hz.transposed()).is_zero()
+
+
+def test_columns():
+    matrix = BinaryMatrix(3, [[0, 2], [1], [0, 1]])
+
+    assert list(matrix.columns()) == [
+        BinaryVector(3, [0, 2]),
+        BinaryVector(3, [1, 2]),
+        BinaryVector(3, [0]),
+    ]
+    assert matrix.column(2) == BinaryVector(3, [0])
+    assert matrix.column_weights() == [2, 2, 1]
+    assert matrix.row_weights() == [2, 1, 2]
+    with pytest.raises(IndexError):
+        matrix.column(3)
+
+
+def test_columns_are_updated_after_editing():
+    matrix = BinaryMatrix(3, [[0, 2], [1], [0, 1]])
+    assert matrix.column(2) == BinaryVector(3, [0])
+
+    matrix.flip(1, 2)
+    assert matrix.column(2) == BinaryVector(3, [0, 1])
+    assert matrix[:, 2] == BinaryVector(3, [0, 1])
+
+    matrix.swap_rows(0, 2)
+    assert matrix.column(2) == BinaryVector(3, [1, 2])
+    assert matrix.column_weights() == [2, 2, 2]
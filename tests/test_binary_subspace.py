@@ -0,0 +1,99 @@
+from qecstruct import (
+    BinaryMatrix,
+    BinarySubspace,
+    BinaryVector,
+    hamming_code,
+    steane_code,
+)
+import pickle
+import pytest
+
+
+def test_basis_is_reduced_echelon_form():
+    subspace = BinarySubspace(4, [[0, 1], [1, 2], [0, 2]])
+
+    assert subspace.dimension() == 2
+    assert subspace.length() == 4
+    assert subspace.basis() == BinaryMatrix(4, [[0, 2], [1, 2]])
+    assert subspace.pivots() == [0, 1]
+
+
+def test_membership():
+    subspace = BinarySubspace(4, [[0, 1], [1, 2]])
+
+    assert BinaryVector(4, [0, 2]) in subspace
+    assert BinaryVector(4, []) in subspace
+    assert BinaryVector(4, [3]) not in subspace
+    assert not subspace.has_vector(BinaryVector(4, [0, 1, 2]))
+    with pytest.raises(ValueError):
+        subspace.has_vector(BinaryVector(3, [0]))
+
+
+def test_equality_does_not_depend_on_generators():
+    first = BinarySubspace(3, [[0, 1], [1, 2]])
+    second = BinarySubspace(3, [[0, 2], [0, 1], [1, 2]])
+
+    assert first == second
+    assert hash(first) == hash(second)
+    assert first != BinarySubspace(3, [[0, 1]])
+
+
+def test_sum_and_intersection():
+    first = BinarySubspace(4, [[0, 1], [2, 3]])
+    second = BinarySubspace(4, [[0, 1, 2, 3], [0]])
+
+    assert first.sum(second) == BinarySubspace(4, [[0], [1], [2, 3]])
+    assert first.intersection(second) == BinarySubspace(4, [[0, 1, 2, 3]])
+    assert first.intersection(BinarySubspace.zero(4)) == BinarySubspace.zero(4)
+    assert first.sum(BinarySubspace.full(4)) == BinarySubspace.full(4)
+    with pytest.raises(ValueError):
+        first.sum(BinarySubspace.zero(3))
+
+
+def test_orthogonal_complement():
+    subspace = BinarySubspace(4, [[0, 1], [1, 2]])
+    complement = subspace.orthogonal_complement()
+
+    assert complement == BinarySubspace(4, [[0, 1, 2], [3]])
+    assert complement.orthogonal_complement() == subspace
+    assert BinarySubspace.zero(3).orthogonal_complement() == BinarySubspace.full(3)
+
+
+def test_quotient():
+    subspace = BinarySubspace.full(3)
+    other = BinarySubspace(3, [[0, 1], [1, 2]])
+
+    assert subspace.quotient_basis(other) == BinaryMatrix(3, [[0]])
+    assert subspace.quotient_dimension(other) == 1
+    assert other.is_subspace_of(subspace)
+    assert not subspace.is_subspace_of(other)
+
+
+def test_pickle():
+    subspace = BinarySubspace(4, [[0, 1], [1, 2]])
+
+    assert pickle.loads(pickle.dumps(subspace)) == subspace
+
+
+def test_linear_code_spaces():
+    code = hamming_code()
+
+    assert code.codespace().dimension() == 4
+    assert code.check_space().dimension() == 3
+    assert code.codespace() == BinarySubspace.row_space(code.gen_mat())
+    assert code.check_space().orthogonal_complement() == code.codespace()
+    assert BinarySubspace.kernel(code.par_mat()) == code.codespace()
+
+
+def test_css_code_spaces():
+    code = steane_code()
+    x_normalizers = code.x_normalizer_space()
+    x_stabilizers = code.x_stabilizer_space()
+
+    assert x_stabilizers.is_subspace_of(x_normalizers)
+    assert code.x_logical_space().sum(x_stabilizers) == x_normalizers
+    assert x_normalizers.quotient_dimension(x_stabilizers) == 1
+    assert x_normalizers.quotient_basis(x_stabilizers).num_rows() == 1
+    assert code.z_logical_space().sum(code.z_stabilizer_space()) == (
+        code.z_normalizer_space()
+    )
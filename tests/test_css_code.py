@@ -0,0 +1,151 @@
+import pytest
+from qecstruct import (
+    BinaryMatrix,
+    Pauli,
+    PauliOperator,
+    hypergraph_product,
+    repetition_code,
+    shor_code,
+    steane_code,
+)
+
+
+def test_syndromes_of_many_errors():
+    code = steane_code()
+    x_rows = [[0], [], [2, 5], [0, 1, 2, 3, 4, 5, 6]]
+    z_rows = [[], [3], [2], [1, 4]]
+    x_syndromes, z_syndromes = code.syndromes_of(
+        BinaryMatrix(7, x_rows), BinaryMatrix(7, z_rows)
+    )
+    assert x_syndromes.shape() == (4, code.num_x_stabs())
+    assert z_syndromes.shape() == (4, code.num_z_stabs())
+    for index in range(4):
+        x_part = x_rows[index]
+        z_part = z_rows[index]
+        positions = sorted(set(x_part) | set(z_part))
+        paulis = []
+        for position in positions:
+            if position in x_part and position in z_part:
+                paulis.append(Pauli.Y())
+            elif position in x_part:
+                paulis.append(Pauli.X())
+            else:
+                paulis.append(Pauli.Z())
+        x_syndrome, z_syndrome = code.syndrome_of(PauliOperator(7, positions, paulis))
+        assert x_syndromes.row(index) == x_syndrome
+        assert z_syndromes.row(index) == z_syndrome
+
+
+def test_syndromes_of_with_wrong_length():
+    with pytest.raises(ValueError):
+        steane_code().syndromes_of(BinaryMatrix(7, [[0]]), BinaryMatrix(6, [[0]]))
+
+
+def test_syndromes_of_with_different_numbers_of_errors():
+    with pytest.raises(ValueError):
+        steane_code().syndromes_of(BinaryMatrix(7, [[0], [1], [2]]), BinaryMatrix(7, [[0]]))
+
+
+def test_equality_and_hash():
+    assert steane_code() == steane_code()
+    assert hash(steane_code()) == hash(steane_code())
+    assert len({steane_code(), steane_code()}) == 1
+
+
+def test_canonical_form_and_equivalence():
+    code = steane_code()
+    canonical = code.canonical_form()
+
+    assert code.is_equivalent_to(canonical)
+    assert canonical.canonical_form() == canonical
+    assert canonical.x_stabs_binary() == code.x_stabilizer_space().basis()
+    assert not code.is_equivalent_to(shor_code())
+
+
+def symplectic_gram(x_logicals, z_logicals):
+    return x_logicals.dot_with_matrix(z_logicals.transposed())
+
+
+@pytest.mark.parametrize("code", [steane_code(), shor_code()])
+def test_paired_logicals(code):
+    x_logicals, z_logicals = code.paired_logicals()
+
+    assert x_logicals.num_rows() == code.num_x_logicals()
+    assert symplectic_gram(x_logicals, z_logicals) == BinaryMatrix.identity(
+        x_logicals.num_rows()
+    )
+    assert code.z_stabs_binary().dot_with_matrix(x_logicals.transposed()).is_zero()
+    assert code.x_stabs_binary().dot_with_matrix(z_logicals.transposed()).is_zero()
+    for logical in x_logicals.rows():
+        assert not code.x_stabilizer_space().has_vector(logical)
+
+
+def test_paired_logicals_of_hypergraph_product():
+    code = hypergraph_product(repetition_code(3), repetition_code(3))
+    x_logicals, z_logicals = code.paired_logicals(minimize_weight=True)
+
+    assert x_logicals.num_rows() == 1
+    assert symplectic_gram(x_logicals, z_logicals) == BinaryMatrix.identity(1)
+    assert x_logicals.row_weights() == [3]
+    assert z_logicals.row_weights() == [3]
+
+
+def test_minimized_logicals_are_not_heavier():
+    code = shor_code()
+    x_logicals, z_logicals = code.paired_logicals()
+    light_x_logicals, light_z_logicals = code.paired_logicals(minimize_weight=True)
+
+    assert symplectic_gram(light_x_logicals, light_z_logicals) == BinaryMatrix.identity(1)
+    assert light_x_logicals.num_ones() <= x_logicals.num_ones()
+    assert light_z_logicals.num_ones() <= z_logicals.num_ones()
+
+
+def x_operator(length, positions):
+    return PauliOperator(length, list(positions), [Pauli.X()] * len(list(positions)))
+
+
+def z_operator(length, positions):
+    return PauliOperator(length, list(positions), [Pauli.Z()] * len(list(positions)))
+
+
+def test_has_stabilizer():
+    code = steane_code()
+    x_stabilizer = list(code.x_stabs_binary().row(0))
+    x_logical = list(code.paired_logicals()[0].row(0))
+
+    assert code.has_stabilizer(x_operator(7, x_stabilizer))
+    assert code.has_stabilizer(PauliOperator(7, [], []))
+    assert not code.has_stabilizer(x_operator(7, x_logical))
+    assert code.has_logical(x_operator(7, x_logical))
+    assert not code.has_stabilizer(x_operator(7, [0]))
+    with pytest.raises(ValueError):
+        code.has_stabilizer(x_operator(6, x_stabilizer[:1]))
+
+
+def test_logical_action():
+    code = shor_code()
+    x_logicals, z_logicals = code.paired_logicals()
+
+    x_action, z_action = code.logical_action(x_operator(9, x_logicals.row(0)))
+    assert list(x_action) == [0]
+    assert list(z_action) == []
+
+    x_action, z_action = code.logical_action(z_operator(9, z_logicals.row(0)))
+    assert list(x_action) == []
+    assert list(z_action) == [0]
+
+    stabilizer = x_operator(9, code.x_stabs_binary().row(0))
+    assert [list(bits) for bits in code.logical_action(stabilizer)] == [[], []]
+
+    with pytest.raises(ValueError):
+        code.logical_action(x_operator(7, [0]))
+
+
+def test_classify():
+    code = steane_code()
+    x_logical = code.paired_logicals()[0].row(0)
+
+    assert code.classify(PauliOperator(7, [], [])) == "trivial"
+    assert code.classify(x_operator(7, code.x_stabs_binary().row(1))) == "stabilizer"
+    assert code.classify(x_operator(7, x_logical)) == "logical"
+    assert code.classify(z_operator(7, [3])) == "not_in_normalizer"
@@ -0,0 +1,102 @@
+import pickle
+import pytest
+from qecstruct import BinaryMatrix, BinaryVector, DenseBinaryMatrix, Rng
+
+
+def random_rows(rng, num_rows, num_columns, probability):
+    return [
+        [column for column in range(num_columns) if rng.rand_bool(probability)]
+        for _ in range(num_rows)
+    ]
+
+
+def reference_rank(rows):
+    basis = []
+    for row in rows:
+        vector = sum(1 << column for column in row)
+        for other in basis:
+            vector = min(vector, vector ^ other)
+        if vector:
+            basis.append(vector)
+    return len(basis)
+
+
+def test_conversions():
+    matrix = BinaryMatrix(5, [[0, 4], [], [1, 2, 3]])
+    dense = DenseBinaryMatrix.from_binary_matrix(matrix)
+    assert dense == DenseBinaryMatrix(5, [[0, 4], [], [1, 2, 3]])
+    assert dense.to_binary_matrix() == matrix
+    assert dense.shape() == (3, 5)
+    assert dense.element(0, 4) == 1
+    assert dense.element(1, 4) == 0
+    assert dense.row(2) == BinaryVector(5, [1, 2, 3])
+
+
+def test_out_of_bound_position():
+    with pytest.raises(ValueError):
+        DenseBinaryMatrix(3, [[3]])
+
+
+@pytest.mark.parametrize(
+    "num_rows, num_columns, probability",
+    [(10, 10, 0.5), (40, 30, 0.1), (70, 150, 0.05), (130, 100, 0.5)],
+)
+def test_rank_nullspace_and_echelon_form(num_rows, num_columns, probability):
+    rng = Rng(num_rows)
+    rows = random_rows(rng, num_rows, num_columns, probability)
+    dense = DenseBinaryMatrix(num_columns, rows)
+    rank = reference_rank(rows)
+    assert dense.rank() == rank
+    nullspace = dense.nullspace()
+    assert nullspace.num_rows() == num_columns - rank
+    assert nullspace.rank() == num_columns - rank
+    assert dense.dot_with_matrix(nullspace.transposed()).is_zero()
+    echelon_form = dense.echelon_form()
+    assert echelon_form.num_rows() == rank
+    echelon_rows = [list(row) for row in echelon_form.to_binary_matrix().rows()]
+    assert reference_rank(rows + echelon_rows) == rank
+
+
+def test_echelon_form_is_reduced():
+    matrix = DenseBinaryMatrix(3, [[0, 1], [1, 2], [0, 2]])
+    assert matrix.echelon_form() == DenseBinaryMatrix(3, [[0, 2], [1, 2]])
+
+
+def test_inverse():
+    rng = Rng(3)
+    size = 80
+    while True:
+        matrix = DenseBinaryMatrix(size, random_rows(rng, size, size, 0.5))
+        if matrix.rank() == size:
+            break
+    inverse = matrix.inverse()
+    assert matrix.dot_with_matrix(inverse) == DenseBinaryMatrix.identity(size)
+    assert inverse.dot_with_matrix(matrix) == DenseBinaryMatrix.identity(size)
+
+
+def test_inverse_of_singular_matrix():
+    with pytest.raises(ValueError):
+        DenseBinaryMatrix(2, [[0, 1], [0, 1]]).inverse()
+    with pytest.raises(ValueError):
+        DenseBinaryMatrix(3, [[0, 1], [0, 2]]).inverse()
+
+
+def test_solve():
+    rng = Rng(5)
+    matrix = DenseBinaryMatrix(90, random_rows(rng, 50, 90, 0.2))
+    solution = BinaryVector(90, [1, 7, 40, 89])
+    rhs = matrix.to_binary_matrix().dot_with_vector(solution)
+    found = matrix.solve(rhs)
+    assert matrix.to_binary_matrix().dot_with_vector(found) == rhs
+
+
+def test_solve_inconsistent_system():
+    matrix = DenseBinaryMatrix(2, [[0, 1], [0, 1]])
+    assert matrix.solve(BinaryVector(2, [0])) is None
+    with pytest.raises(ValueError):
+        matrix.solve(BinaryVector(3, [0]))
+
+
+def test_pickle():
+    matrix = DenseBinaryMatrix(70, [[0, 65], [3]])
+    assert pickle.loads(pickle.dumps(matrix)) == matrix
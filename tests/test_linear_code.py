@@ -1,7 +1,10 @@
+import pickle
 from qecstruct import (
     LinearCode, 
     hamming_code, repetition_code, random_regular_code,
-    BinaryVector, BinaryMatrix
+    progressive_edge_growth_code, irregular_code,
+    bch_code, reed_muller_code, golay_code,
+    BinaryVector, BinaryMatrix, Rng
 )
 import pytest
 
@@ -71,3 +74,325 @@ def random_code(num_bits, num_checks, bit_deg, check_deg):
     assert code.dimension() >= num_bits - num_checks 
     assert code.num_checks() == num_checks
     assert code.num_generators() == num_bits - num_checks
+
+
+def test_quasi_cyclic_code():
+    code = LinearCode.quasi_cyclic([[0, 1, -1], [2, -1, 0]], 3)
+
+    assert len(code) == 9
+    assert code.num_checks() == 6
+    assert code.par_mat() == BinaryMatrix(
+        9,
+        [
+            [0, 4],
+            [1, 5],
+            [2, 3],
+            [2, 6],
+            [0, 7],
+            [1, 8],
+        ]
+    )
+
+
+def test_quasi_cyclic_code_with_invalid_shift():
+    with pytest.raises(ValueError):
+        LinearCode.quasi_cyclic([[0, 3]], 3)
+    with pytest.raises(ValueError):
+        LinearCode.quasi_cyclic([[0, 1], [1]], 3)
+
+
+def test_progressive_edge_growth_code():
+    bit_degrees = [2, 2, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3]
+    code = progressive_edge_growth_code(9, bit_degrees, Rng(123))
+
+    assert len(code) == 12
+    assert code.num_checks() == 9
+    bit_adjacencies = code.par_mat().transposed()
+    for bit, degree in enumerate(bit_degrees):
+        assert bit_adjacencies.row(bit).weight() == degree
+
+    same_code = progressive_edge_growth_code(9, bit_degrees, Rng(123))
+    assert code.par_mat() == same_code.par_mat()
+
+
+def test_progressive_edge_growth_code_avoids_four_cycles():
+    code = progressive_edge_growth_code(6, [2] * 12, Rng(123))
+
+    assert code.girth() >= 6
+
+
+def test_progressive_edge_growth_code_with_too_large_degree():
+    with pytest.raises(ValueError):
+        progressive_edge_growth_code(2, [3, 2], Rng(123))
+
+
+def test_irregular_code():
+    code = irregular_code(100, [0, 0.5, 0.5], [0, 0, 0, 0, 0, 1], Rng(42))
+
+    assert len(code) == 100
+    assert code.num_checks() == 40
+    bit_degrees = [row.weight() for row in code.par_mat().transposed().rows()]
+    assert bit_degrees.count(2) == 60
+    assert bit_degrees.count(3) == 40
+    assert all(row.weight() == 6 for row in code.par_mat().rows())
+
+
+def test_irregular_code_with_invalid_distribution():
+    with pytest.raises(ValueError):
+        irregular_code(100, [0, 0.5, 0.6], [0, 0, 0, 0, 0, 1], Rng(42))
+    with pytest.raises(ValueError):
+        irregular_code(100, [0, 1.5, -0.5], [0, 0, 0, 0, 0, 1], Rng(42))
+    with pytest.raises(ValueError):
+        irregular_code(100, [0, float("nan"), 1.0], [0, 0, 0, 0, 0, 1], Rng(1))
+    with pytest.raises(ValueError):
+        irregular_code(100, [0, 0.5, 0.5], [0, 0, 0, 0, float("inf"), 1], Rng(1))
+
+
+def test_tanner_graph_analysis_of_hamming_code():
+    code = hamming_code()
+
+    assert code.girth() == 4
+    assert code.num_cycles(4) == 3
+    assert code.num_cycles(6) == 4
+    assert code.num_cycles(8) == 0
+    assert code.bit_degree_distribution() == [0, 3, 3, 1]
+    assert code.check_degree_distribution() == [0, 0, 0, 0, 3]
+    assert code.stopping_sets(2) == []
+    assert len(code.stopping_sets(3)) == 10
+    assert [0, 1, 2] in code.stopping_sets(3)
+    assert ([0], 1) in code.trapping_sets(1, 1)
+
+
+def test_girth_of_acyclic_code():
+    code = repetition_code(4)
+
+    assert code.girth() == -1
+    assert code.num_cycles(4) == 0
+    with pytest.raises(ValueError):
+        code.num_cycles(5)
+
+
+@pytest.mark.parametrize("order, length, dimension", [(2, 3, 1), (3, 7, 4), (4, 15, 11)])
+def test_hamming_code_of_any_order(order, length, dimension):
+    code = hamming_code(order)
+
+    assert len(code) == length
+    assert code.dimension() == dimension
+    assert code.minimal_distance() == 3
+
+
+@pytest.mark.parametrize(
+    "order, designed_distance, length, dimension, distance",
+    [(3, 3, 7, 4, 3), (4, 5, 15, 7, 5), (4, 7, 15, 5, 7), (5, 7, 31, 16, 7)],
+)
+def test_bch_code(order, designed_distance, length, dimension, distance):
+    code = bch_code(order, designed_distance)
+
+    assert len(code) == length
+    assert code.dimension() == dimension
+    assert code.minimal_distance() == distance
+
+
+@pytest.mark.parametrize(
+    "degree, num_variables, dimension, distance",
+    [(0, 3, 1, 8), (1, 3, 4, 4), (1, 4, 5, 8), (2, 4, 11, 4), (2, 5, 16, 8)],
+)
+def test_reed_muller_code(degree, num_variables, dimension, distance):
+    code = reed_muller_code(degree, num_variables)
+
+    assert len(code) == 2**num_variables
+    assert code.dimension() == dimension
+    assert code.minimal_distance() == distance
+
+
+def test_golay_codes():
+    extended = golay_code()
+    assert len(extended) == 24
+    assert extended.dimension() == 12
+    assert extended.minimal_distance() == 8
+
+    perfect = golay_code(extended=False)
+    assert len(perfect) == 23
+    assert perfect.dimension() == 12
+    assert perfect.minimal_distance() == 7
+
+
+def test_invalid_catalog_parameters():
+    with pytest.raises(ValueError):
+        hamming_code(1)
+    with pytest.raises(ValueError):
+        bch_code(17, 3)
+    with pytest.raises(ValueError):
+        bch_code(4, 16)
+    with pytest.raises(ValueError):
+        reed_muller_code(4, 3)
+    with pytest.raises(ValueError):
+        reed_muller_code(0, 20)
+
+
+def test_syndromes_of_many_messages():
+    code = hamming_code(4)
+    rng = Rng(7)
+    rows = [[bit for bit in range(15) if rng.rand_bool(0.3)] for _ in range(150)]
+    syndromes = code.syndromes_of(BinaryMatrix(15, rows))
+    assert syndromes.shape() == (150, 4)
+    for row, syndrome in zip(rows, syndromes.rows()):
+        assert syndrome == code.syndrome_of(BinaryVector(15, row))
+
+
+def test_syndromes_of_with_wrong_length():
+    with pytest.raises(ValueError):
+        repetition_code(3).syndromes_of(BinaryMatrix(4, [[0]]))
+
+
+def test_syndromes_of_dense_array():
+    np = pytest.importorskip("numpy")
+    code = repetition_code(3)
+    errors = np.array([[1, 0, 0], [0, 1, 0], [1, 1, 1]])
+    expected = BinaryMatrix(2, [[0], [0, 1], []])
+    assert code.syndromes_of(errors) == expected
+
+
+def test_equality_and_hash():
+    code = LinearCode(BinaryMatrix(3, [[0, 1], [1, 2]]))
+    same = LinearCode(BinaryMatrix(3, [[0, 1], [1, 2]]))
+    other = LinearCode(BinaryMatrix(3, [[0, 2], [0, 1], [1, 2]]))
+
+    assert code == same
+    assert hash(code) == hash(same)
+    assert code != other
+    assert len({code, same}) == 1
+
+
+def test_canonical_form():
+    code = LinearCode(BinaryMatrix(3, [[0, 1], [1, 2]]))
+    other = LinearCode(BinaryMatrix(3, [[0, 2], [0, 1], [1, 2]]))
+
+    assert code.canonical_par_mat() == BinaryMatrix(3, [[0, 2], [1, 2]])
+    assert code.canonical_gen_mat() == BinaryMatrix(3, [[0, 1, 2]])
+    assert code.canonical_form() == other.canonical_form()
+    assert len({code.canonical_form(), other.canonical_form()}) == 1
+    assert code.has_same_codespace(other)
+
+
+def test_has_same_codespace_is_symmetric():
+    code = repetition_code(3)
+    smaller = LinearCode(BinaryMatrix(3, [[0], [1], [2]]))
+
+    assert not code.has_same_codespace(smaller)
+    assert not smaller.has_same_codespace(code)
+
+
+def test_from_generator_matrix():
+    generators = BinaryMatrix(7, [[0, 4, 5, 6], [1, 4, 5], [2, 4, 6], [3, 5, 6]])
+    code = LinearCode(gen_mat=generators)
+
+    assert code.gen_mat() == generators
+    assert code.dimension() == 4
+    assert code.num_checks() == 3
+    assert code.par_mat().dot_with_matrix(generators.transposed()).is_zero()
+    checks = BinaryMatrix(7, [[0, 1, 2, 4], [0, 1, 3, 5], [0, 2, 3, 6]])
+    assert code.has_same_codespace(LinearCode(checks))
+
+
+def test_from_generator_matrix_of_repetition_code():
+    code = LinearCode(gen_mat=BinaryMatrix(3, [[0, 1, 2]]))
+
+    assert code.dimension() == 1
+    assert code.has_same_codespace(repetition_code(3))
+
+
+def test_from_redundant_generator_matrix():
+    with pytest.raises(ValueError, match="redundant"):
+        LinearCode(gen_mat=BinaryMatrix(3, [[0, 1], [1, 2], [0, 2]]))
+
+
+def test_from_both_matrices_keeps_them():
+    checks = BinaryMatrix(3, [[0, 1], [0, 2], [1, 2]])
+    generators = BinaryMatrix(3, [[0, 1, 2]])
+    code = LinearCode(checks, generators)
+
+    assert code.par_mat() == checks
+    assert code.gen_mat() == generators
+
+    unpickled = pickle.loads(pickle.dumps(code))
+    assert unpickled.gen_mat() == generators
+    assert unpickled == code
+
+
+def test_unpickle_code_without_stored_generator_matrix():
+    # State of repetition_code(3) pickled before generator matrices were stored.
+    state = pickle.dumps(
+        {
+            "parity_check_matrix": {"number_of_columns": 3, "rows": [[0, 1], [1, 2]]},
+            "generator_matrix": {"number_of_columns": 3, "rows": [[0, 1, 2]]},
+            "bit_adjacencies": {"number_of_columns": 2, "rows": [[0], [0, 1], [1]]},
+        },
+        protocol=3,
+    )
+    code = LinearCode()
+    code.__setstate__(state)
+
+    assert code == repetition_code(3)
+    assert code.gen_mat() == BinaryMatrix(3, [[0, 1, 2]])
+
+
+def test_from_both_matrices_with_missing_generators():
+    checks = BinaryMatrix(4, [[0, 1]])
+    generators = BinaryMatrix(4, [[2], [3]])
+
+    with pytest.raises(ValueError, match="nullspace"):
+        LinearCode(checks, generators)
+
+
+def test_from_non_orthogonal_matrices():
+    with pytest.raises(ValueError, match="orthogonal"):
+        LinearCode(BinaryMatrix(3, [[0, 1]]), BinaryMatrix(3, [[0, 2]]))
+    with pytest.raises(ValueError, match="columns"):
+        LinearCode(BinaryMatrix(3, [[0, 1]]), BinaryMatrix(4, [[2]]))
+
+
+def test_information_set():
+    assert repetition_code(3).information_set() == [0]
+    assert len(hamming_code().information_set()) == 4
+
+
+def test_encode_is_systematic():
+    code = hamming_code()
+    information_set = code.information_set()
+    for positions in [[], [0], [1, 3], [0, 1, 2, 3]]:
+        message = BinaryVector(4, positions)
+        codeword = code.encode(message)
+        assert code.has_codeword(codeword)
+        bits = [codeword.element(i) for i in information_set]
+        assert [i for i, bit in enumerate(bits) if bit] == list(message)
+        assert code.unencode(codeword) == message
+
+
+def test_encode_with_wrong_length():
+    with pytest.raises(ValueError):
+        hamming_code().encode(BinaryVector(3, [0]))
+
+
+def test_unencode_non_codeword():
+    with pytest.raises(ValueError):
+        repetition_code(3).unencode(BinaryVector(3, [0]))
+
+
+def test_encode_many():
+    code = hamming_code()
+    messages = BinaryMatrix(4, [[], [0, 2], [1, 2, 3]])
+    codewords = code.encode_many(messages)
+
+    assert codewords.shape() == (3, 7)
+    assert code.syndromes_of(codewords).is_zero()
+    for message, codeword in zip(messages.rows(), codewords.rows()):
+        assert code.encode(message) == codeword
+    assert code.unencode_many(codewords) == messages
+
+
+def test_unencode_many_with_non_codeword():
+    code = repetition_code(3)
+
+    with pytest.raises(ValueError, match="row 1"):
+        code.unencode_many(BinaryMatrix(3, [[0, 1, 2], [1]]))
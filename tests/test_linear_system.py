@@ -0,0 +1,125 @@
+import pickle
+import pytest
+from qecstruct import BinaryMatrix, BinaryVector, Rng, hamming_code
+
+
+def random_matrix(rng, num_rows, num_columns, probability):
+    rows = [
+        [column for column in range(num_columns) if rng.rand_bool(probability)]
+        for _ in range(num_rows)
+    ]
+    return BinaryMatrix(num_columns, rows)
+
+
+def test_solve():
+    matrix = BinaryMatrix(4, [[0, 1], [1, 2], [2, 3]])
+    assert matrix.solve(BinaryVector(3, [0])) == BinaryVector(4, [0])
+    assert matrix.solve(BinaryVector(3, [0, 2])) == BinaryVector(4, [1, 2])
+
+
+def test_solve_inconsistent_system():
+    matrix = BinaryMatrix(3, [[0, 1], [0, 1]])
+    assert matrix.solve(BinaryVector(2, [1])) is None
+    assert matrix.solve_all(BinaryVector(2, [1])) is None
+
+
+def test_solve_with_wrong_length():
+    with pytest.raises(ValueError):
+        BinaryMatrix(3, [[0, 1]]).solve(BinaryVector(2, [0]))
+
+
+def test_solve_all():
+    matrix = hamming_code().par_mat()
+    syndrome = BinaryVector(3, [0, 2])
+    solution, nullspace = matrix.solve_all(syndrome)
+    assert matrix.dot_with_vector(solution) == syndrome
+    assert nullspace.num_rows() == 4
+    assert matrix.dot_with_matrix(nullspace.transposed()).is_zero()
+
+
+@pytest.mark.parametrize("num_rows, num_columns", [(20, 40), (60, 100), (100, 70)])
+def test_factorization_solves_many_systems(num_rows, num_columns):
+    rng = Rng(num_columns)
+    matrix = random_matrix(rng, num_rows, num_columns, 0.1)
+    factorization = matrix.factorize()
+    errors = random_matrix(rng, 50, num_columns, 0.05)
+    syndromes = errors.dot_with_matrix(matrix.transposed())
+    solutions = factorization.solve_many(syndromes)
+    for syndrome, solution in zip(syndromes.rows(), solutions):
+        assert matrix.dot_with_vector(solution) == syndrome
+
+
+def test_factorization():
+    matrix = BinaryMatrix(4, [[0, 1, 2], [1, 3], [0, 2, 3], [1, 2]])
+    factorization = matrix.factorize()
+    assert factorization.rank() == 3
+    assert factorization.pivots() == [0, 1, 2]
+    transform = factorization.transform()
+    assert transform.dot_with_matrix(matrix) == factorization.echelon_form()
+    assert transform.dot_with_matrix(transform).rank() == 4
+    nullspace = factorization.nullspace()
+    assert nullspace.num_rows() == 1
+    assert matrix.dot_with_matrix(nullspace.transposed()).is_zero()
+
+
+def test_factorization_pickle():
+    matrix = BinaryMatrix(4, [[0, 1, 2], [1, 3], [0, 2, 3], [1, 2]])
+    factorization = matrix.factorize()
+    unpickled = pickle.loads(pickle.dumps(factorization))
+    assert unpickled.pivots() == factorization.pivots()
+    assert unpickled.transform() == factorization.transform()
+    rhs = BinaryVector(4, [0, 3])
+    assert unpickled.solve(rhs) == factorization.solve(rhs)
+
+    with pytest.raises(ValueError):
+        factorization.__setstate__(b"garbage")
+
+
+def test_reduced_echelon_form_and_pivots():
+    matrix = BinaryMatrix(4, [[0, 1, 2], [1, 3], [0, 2], [0, 2, 3]])
+    assert matrix.reduced_echelon_form() == BinaryMatrix(4, [[0, 2], [1], [3]])
+    assert matrix.pivots() == [0, 1, 3]
+    assert matrix.pivot_permutation() == [0, 1, 3, 2]
+
+
+def test_reduced_echelon_form_with_transform():
+    rng = Rng(11)
+    matrix = random_matrix(rng, 30, 80, 0.1)
+    echelon_form, pivots, transform = matrix.reduced_echelon_form_with_transform()
+    assert echelon_form.shape() == (30, 80)
+    assert transform.shape() == (30, 30)
+    assert transform.dot_with_matrix(matrix) == echelon_form
+    for row, pivot in enumerate(pivots):
+        assert echelon_form.row(row).non_trivial_position(0) == pivot
+        assert [echelon_form.element(other, pivot) for other in range(30)].count(1) == 1
+
+
+def test_inverse():
+    rng = Rng(13)
+    while True:
+        matrix = random_matrix(rng, 20, 20, 0.5)
+        if len(matrix.pivots()) == 20:
+            break
+    inverse = matrix.inverse()
+    assert matrix.dot_with_matrix(inverse) == BinaryMatrix.identity(20)
+    assert inverse.dot_with_matrix(matrix) == BinaryMatrix.identity(20)
+
+
+def test_inverse_of_singular_or_rectangular_matrix():
+    with pytest.raises(ValueError):
+        BinaryMatrix(2, [[0, 1], [0, 1]]).inverse()
+    with pytest.raises(ValueError):
+        BinaryMatrix(3, [[0, 1], [1, 2]]).inverse()
+
+
+def test_right_and_left_inverses():
+    matrix = hamming_code().par_mat()
+    right_inverse = matrix.right_inverse()
+    assert matrix.dot_with_matrix(right_inverse) == BinaryMatrix.identity(3)
+    transposed = matrix.transposed()
+    left_inverse = transposed.left_inverse()
+    assert left_inverse.dot_with_matrix(transposed) == BinaryMatrix.identity(3)
+    with pytest.raises(ValueError):
+        transposed.right_inverse()
+    with pytest.raises(ValueError):
+        matrix.left_inverse()
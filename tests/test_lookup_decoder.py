@@ -0,0 +1,132 @@
+import itertools
+import pickle
+import pytest
+import struct
+from qecstruct import (
+    BinaryVector,
+    LookupDecoder,
+    Pauli,
+    PauliOperator,
+    hamming_code,
+    hypergraph_product,
+    repetition_code,
+    shor_code,
+    steane_code,
+)
+
+
+def test_hamming_code_corrects_single_errors():
+    code = hamming_code()
+    decoder = LookupDecoder(code)
+
+    assert decoder.num_syndromes() == 8
+    assert decoder.max_weight() == 1
+    assert repr(decoder) == "LookupDecoder with 8 syndromes up to weight 1"
+    for position in range(7):
+        error = BinaryVector(7, [position])
+        assert decoder.decode(code.syndrome_of(error)) == error
+
+
+def test_corrections_have_minimum_weight():
+    code = repetition_code(5)
+    decoder = LookupDecoder(code)
+
+    for positions in itertools.combinations(range(5), 2):
+        error = BinaryVector(5, list(positions))
+        correction = decoder.decode(code.syndrome_of(error))
+        assert correction == error
+
+
+def test_max_weight_limits_the_table():
+    code = repetition_code(5)
+    decoder = LookupDecoder(code, max_weight=1)
+
+    assert decoder.num_syndromes() == 6
+    assert decoder.max_weight() == 1
+    error = BinaryVector(5, [0, 2])
+    assert decoder.decode(code.syndrome_of(error)) is None
+
+
+def test_syndrome_with_wrong_length():
+    decoder = LookupDecoder(hamming_code())
+
+    with pytest.raises(ValueError):
+        decoder.decode(BinaryVector(4, [0]))
+
+
+@pytest.mark.parametrize("code", [steane_code(), shor_code()])
+def test_css_code_corrects_single_qubit_errors(code):
+    decoder = LookupDecoder(code)
+    length = code.length()
+
+    for position in range(length):
+        for pauli in [Pauli.X(), Pauli.Y(), Pauli.Z()]:
+            error = PauliOperator(length, [position], [pauli])
+            correction = decoder.decode(code.syndrome_of(error))
+            residual = error.apply(correction)
+            assert code.classify(residual) in ["trivial", "stabilizer"]
+
+
+def test_hypergraph_product():
+    code = hypergraph_product(repetition_code(3), repetition_code(3))
+    decoder = LookupDecoder(code)
+    error = PauliOperator(code.length(), [4], [Pauli.X()])
+
+    correction = decoder.decode(code.syndrome_of(error))
+    assert code.classify(error.apply(correction)) in ["trivial", "stabilizer"]
+
+
+def test_invalid_code():
+    with pytest.raises(TypeError):
+        LookupDecoder(BinaryVector(3, [0]))
+
+
+def test_pickle():
+    code = steane_code()
+    decoder = LookupDecoder(code)
+    unpickled = pickle.loads(pickle.dumps(decoder))
+    error = PauliOperator(7, [2], [Pauli.Y()])
+
+    assert unpickled.num_syndromes() == decoder.num_syndromes()
+    correction = unpickled.decode(code.syndrome_of(error))
+    assert repr(correction) == repr(decoder.decode(code.syndrome_of(error)))
+    assert code.classify(error.apply(correction)) in ["trivial", "stabilizer"]
+
+
+def test_unpickle_invalid_state():
+    decoder = LookupDecoder()
+
+    assert decoder.num_syndromes() == 0
+    with pytest.raises(ValueError):
+        decoder.__setstate__(b"garbage")
+
+
+# The bincode state of a linear code table whose checks are all zeros.
+def classical_state(length, num_checks, corrections):
+    words_per_row = (num_checks + 63) // 64
+    state = struct.pack("<I4Q", 0, length, num_checks, words_per_row, length * words_per_row)
+    state += bytes(8 * length * words_per_row)
+    state += struct.pack("<2Q", 0, len(corrections))
+    for syndrome, correction in corrections:
+        state += struct.pack(f"<Q{len(syndrome)}Q", len(syndrome), *syndrome)
+        state += struct.pack(f"<Q{len(correction)}Q", len(correction), *correction)
+    return state
+
+
+def test_unpickle_valid_handmade_state():
+    decoder = LookupDecoder()
+    decoder.__setstate__(classical_state(3, 2, [([0], [])]))
+
+    assert decoder.num_syndromes() == 1
+    assert decoder.decode(BinaryVector(2, [])) == BinaryVector(3, [])
+
+
+@pytest.mark.parametrize(
+    "corrections",
+    [[([0], [3])], [([0], [1, 0])], [([0, 0], [0])], [([], [0])]],
+)
+def test_unpickle_state_with_invalid_entries(corrections):
+    decoder = LookupDecoder()
+    with pytest.raises(ValueError):
+        decoder.__setstate__(classical_state(3, 2, corrections))
+    assert decoder.num_syndromes() == 0
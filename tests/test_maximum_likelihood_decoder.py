@@ -0,0 +1,133 @@
+import itertools
+import pytest
+from qecstruct import (
+    BiasedNoise,
+    BinarySymmetricChannel,
+    BinaryVector,
+    DepolarizingNoise,
+    MaximumLikelihoodDecoder,
+    Pauli,
+    PauliOperator,
+    hypergraph_product,
+    repetition_code,
+    shor_code,
+    steane_code,
+)
+
+PAULIS = [None, Pauli.X(), Pauli.Y(), Pauli.Z()]
+
+
+def all_paulis(length):
+    for labels in itertools.product(range(4), repeat=length):
+        positions = [position for position, label in enumerate(labels) if label]
+        paulis = [PAULIS[label] for label in labels if label]
+        yield PauliOperator(length, positions, paulis)
+
+
+def depolarizing_probability(error, probability):
+    weight = error.weight()
+    return (probability / 3) ** weight * (1 - probability) ** (error.len() - weight)
+
+
+def biased_probability(error, probability, bias):
+    z_probability = probability * bias / (1 + bias)
+    x_probability = (probability - z_probability) / 2
+    x_part = set(error.x_part().non_trivial_positions())
+    z_part = set(error.z_part().non_trivial_positions())
+    num_z = len(z_part - x_part)
+    num_others = len(x_part)
+    return (
+        z_probability**num_z
+        * x_probability**num_others
+        * (1 - probability) ** (error.len() - num_z - num_others)
+    )
+
+
+def as_tuple(syndrome):
+    return tuple(tuple(part) for part in syndrome)
+
+
+@pytest.mark.parametrize(
+    "code", [steane_code(), shor_code(), hypergraph_product(repetition_code(3), repetition_code(3))]
+)
+def test_single_qubit_errors_are_corrected(code):
+    decoder = MaximumLikelihoodDecoder(code, DepolarizingNoise(0.05))
+    for position in range(code.length()):
+        for pauli in PAULIS[1:]:
+            error = PauliOperator(code.length(), [position], [pauli])
+            correction = decoder.decode(code.syndrome_of(error))
+            assert code.classify(error.apply(correction)) in ("trivial", "stabilizer")
+
+
+@pytest.mark.parametrize(
+    "noise, probability_of",
+    [
+        (DepolarizingNoise(0.2), lambda error: depolarizing_probability(error, 0.2)),
+        (BiasedNoise(0.2, 10), lambda error: biased_probability(error, 0.2, 10)),
+    ],
+)
+def test_coset_probabilities_match_brute_force(noise, probability_of):
+    code = steane_code()
+    decoder = MaximumLikelihoodDecoder(code, noise)
+    cosets = {}
+    for error in all_paulis(7):
+        key = (as_tuple(code.syndrome_of(error)), as_tuple(code.logical_action(error)))
+        cosets[key] = cosets.get(key, 0) + probability_of(error)
+
+    error = PauliOperator(7, [0, 3], [Pauli.X(), Pauli.Z()])
+    syndrome = code.syndrome_of(error)
+    expected = sorted(
+        value for (key, value) in cosets.items() if key[0] == as_tuple(syndrome)
+    )
+    total = sum(expected)
+    probabilities = decoder.coset_probabilities(syndrome)
+
+    assert len(probabilities) == 4
+    assert sum(probabilities) == pytest.approx(1)
+    for value, expected_value in zip(sorted(probabilities), expected):
+        assert value == pytest.approx(expected_value / total)
+
+    correction = decoder.decode(syndrome)
+    best = max(
+        (value, key[1]) for (key, value) in cosets.items() if key[0] == as_tuple(syndrome)
+    )
+    assert as_tuple(code.logical_action(correction)) == best[1]
+
+
+def test_bit_and_phase_flip_noise_decodes_independently():
+    code = shor_code()
+    decoder = MaximumLikelihoodDecoder(code, BinarySymmetricChannel(0.1))
+    error = PauliOperator(9, [0, 4], [Pauli.X(), Pauli.Z()])
+    correction = decoder.decode(code.syndrome_of(error))
+
+    assert code.classify(error.apply(correction)) in ("trivial", "stabilizer")
+    assert len(decoder.coset_probabilities(code.syndrome_of(error))) == 4
+
+
+def test_biased_noise_picks_another_coset_than_depolarizing_noise():
+    code = steane_code()
+    error = PauliOperator(7, [0, 2], [Pauli.Z(), Pauli.X()])
+    syndrome = code.syndrome_of(error)
+
+    depolarizing_decoder = MaximumLikelihoodDecoder(code, DepolarizingNoise(0.1))
+    correction = depolarizing_decoder.decode(syndrome)
+    assert code.classify(error.apply(correction)) == "logical"
+
+    biased_decoder = MaximumLikelihoodDecoder(code, BiasedNoise(0.1, 10))
+    correction = biased_decoder.decode(syndrome)
+    assert code.classify(error.apply(correction)) == "stabilizer"
+
+
+def test_invalid_inputs():
+    code = steane_code()
+    with pytest.raises(TypeError):
+        MaximumLikelihoodDecoder(code, 0.1)
+
+    decoder = MaximumLikelihoodDecoder(code, DepolarizingNoise(0.1))
+    with pytest.raises(ValueError):
+        decoder.decode((BinaryVector(2, []), BinaryVector(3, [])))
+    with pytest.raises(ValueError):
+        MaximumLikelihoodDecoder(
+            hypergraph_product(repetition_code(5), repetition_code(5)),
+            DepolarizingNoise(0.1),
+        )
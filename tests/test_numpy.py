@@ -0,0 +1,82 @@
+import pytest
+from qecstruct import BinaryMatrix, BinaryVector, to_dense
+
+np = pytest.importorskip("numpy")
+
+
+def test_matrix_to_dense():
+    matrix = BinaryMatrix(3, [[0, 2], [1]])
+    expected = np.array([[1, 0, 1], [0, 1, 0]], dtype=np.uint8)
+    assert np.array_equal(matrix.to_dense(), expected)
+    assert np.array_equal(to_dense(matrix), expected)
+    assert matrix.to_dense().dtype == np.uint8
+
+
+def test_matrix_from_dense():
+    array = np.array([[1, 0, 1], [0, 1, 0]])
+    assert BinaryMatrix.from_dense(array) == BinaryMatrix(3, [[0, 2], [1]])
+    assert BinaryMatrix.from_dense(array.astype(bool)) == BinaryMatrix(3, [[0, 2], [1]])
+
+
+def test_matrix_from_dense_round_trip():
+    matrix = BinaryMatrix(5, [[0, 4], [], [1, 2, 3]])
+    assert BinaryMatrix.from_dense(matrix.to_dense()) == matrix
+
+
+def test_matrix_from_dense_with_invalid_element():
+    with pytest.raises(ValueError):
+        BinaryMatrix.from_dense(np.array([[0, 2]]))
+
+
+def test_matrix_from_dense_with_wrong_dimension():
+    with pytest.raises(ValueError):
+        BinaryMatrix.from_dense(np.array([0, 1]))
+
+
+def test_vector_dense_round_trip():
+    vector = BinaryVector(4, [1, 3])
+    assert np.array_equal(vector.to_dense(), np.array([0, 1, 0, 1]))
+    assert np.array_equal(to_dense(vector), np.array([0, 1, 0, 1]))
+    assert BinaryVector.from_dense(vector.to_dense()) == vector
+
+
+def test_batched_vectors():
+    vectors = [BinaryVector(3, [0]), BinaryVector(3, [1, 2])]
+    expected = np.array([[1, 0, 0], [0, 1, 1]])
+    assert np.array_equal(BinaryVector.batch_to_dense(vectors), expected)
+    assert np.array_equal(to_dense(vectors), expected)
+    assert BinaryVector.batch_from_dense(expected) == vectors
+
+
+def test_batched_vectors_with_different_lengths():
+    with pytest.raises(ValueError):
+        BinaryVector.batch_to_dense([BinaryVector(3, [0]), BinaryVector(4, [0])])
+
+
+def test_csr_round_trip():
+    sparse = pytest.importorskip("scipy.sparse")
+    matrix = BinaryMatrix(4, [[0, 2], [], [1, 3]])
+    csr = matrix.to_csr()
+    assert isinstance(csr, sparse.csr_matrix)
+    assert csr.shape == (3, 4)
+    assert list(csr.indptr) == [0, 2, 2, 4]
+    assert list(csr.indices) == [0, 2, 1, 3]
+    assert BinaryMatrix.from_csr(csr) == matrix
+
+
+def test_from_csr_with_unsorted_indices_and_explicit_zeros():
+    sparse = pytest.importorskip("scipy.sparse")
+    csr = sparse.csr_matrix(
+        (np.array([1, 1, 0]), np.array([2, 0, 1]), np.array([0, 3])), shape=(1, 3)
+    )
+    assert BinaryMatrix.from_csr(csr) == BinaryMatrix(3, [[0, 2]])
+
+
+@pytest.mark.parametrize(
+    "array", [np.array([[0, 256]]), np.array([[257, 1]]), np.array([[0.7, 1.0]])]
+)
+def test_matrix_from_dense_is_validated_before_the_cast(array):
+    with pytest.raises(ValueError):
+        BinaryMatrix.from_dense(array)
+    with pytest.raises(ValueError):
+        BinaryVector.from_dense(array[0])
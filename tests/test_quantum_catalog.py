@@ -0,0 +1,195 @@
+import pickle
+import pytest
+from qecstruct import (
+    BinaryMatrix,
+    BinaryVector,
+    Pauli,
+    PauliOperator,
+    StabilizerCode,
+    bacon_shor_code,
+    five_qubit_code,
+    four_two_two_code,
+    planar_surface_code,
+    quantum_reed_muller_code,
+    reed_muller_css_code,
+    reed_muller_triorthogonal_code,
+    triorthogonal_code,
+)
+
+X, Y, Z = Pauli.X(), Pauli.Y(), Pauli.Z()
+
+
+def test_stabilizer_code():
+    code = StabilizerCode(
+        [PauliOperator(3, [0, 1], [Z, Z]), PauliOperator(3, [1, 2], [Z, Z])]
+    )
+    assert len(code) == 3
+    assert code.num_stabs() == 2
+    assert code.num_logicals() == 1
+    error = PauliOperator(3, [1], [X])
+    assert code.syndrome_of(error) == BinaryVector(2, [0, 1])
+    assert code.has_stabilizer(PauliOperator(3, [0, 2], [Z, Z]))
+    assert not code.has_stabilizer(PauliOperator(3, [0], [Z]))
+    assert code.has_logical(PauliOperator(3, [0, 1, 2], [X, X, X]))
+    assert not code.has_logical(error)
+
+
+def test_stabilizer_code_with_non_commuting_stabilizers():
+    with pytest.raises(ValueError):
+        StabilizerCode([PauliOperator(2, [0], [X]), PauliOperator(2, [0], [Z])])
+
+
+def test_stabilizer_code_with_different_lengths():
+    with pytest.raises(ValueError):
+        StabilizerCode([PauliOperator(2, [0], [X]), PauliOperator(3, [0], [X])])
+
+
+def test_five_qubit_code():
+    code = five_qubit_code()
+    assert len(code) == 5
+    assert code.num_stabs() == 4
+    assert code.num_logicals() == 1
+    logical_x = PauliOperator(5, list(range(5)), [X] * 5)
+    logical_z = PauliOperator(5, list(range(5)), [Z] * 5)
+    assert code.has_logical(logical_x)
+    assert code.has_logical(logical_z)
+    # Every single qubit error has a distinct non-trivial syndrome.
+    syndromes = set()
+    for position in range(5):
+        for pauli in [X, Y, Z]:
+            syndrome = code.syndrome_of(PauliOperator(5, [position], [pauli]))
+            syndromes.add(syndrome)
+    assert len(syndromes) == 15
+    assert BinaryVector.zeros(4) not in syndromes
+
+
+def test_five_qubit_code_pickle():
+    code = five_qubit_code()
+    other = pickle.loads(pickle.dumps(code))
+    assert other.stabs_binary() == code.stabs_binary()
+
+
+def test_four_two_two_code():
+    code = four_two_two_code()
+    assert len(code) == 4
+    assert code.num_x_logicals() == 2
+    assert code.num_z_logicals() == 2
+
+
+def test_bacon_shor_code():
+    code = bacon_shor_code(3, 4)
+    assert len(code) == 12
+    assert code.num_x_stabs() == 2
+    assert code.num_z_stabs() == 3
+    # One logical qubit and 6 gauge qubits.
+    assert code.num_x_logicals() == 7
+
+
+def test_bacon_shor_code_too_small():
+    with pytest.raises(ValueError):
+        bacon_shor_code(1, 3)
+
+
+@pytest.mark.parametrize("order", [3, 4, 5])
+def test_quantum_reed_muller_code(order):
+    code = quantum_reed_muller_code(order)
+    assert len(code) == 2 ** order - 1
+    assert code.num_x_stabs() == order
+    assert code.num_x_logicals() == 1
+    assert code.num_z_logicals() == 1
+
+
+def test_quantum_reed_muller_code_invalid_order():
+    with pytest.raises(ValueError):
+        quantum_reed_muller_code(2)
+
+
+def test_triorthogonal_code_from_reed_muller():
+    # The all ones row together with the Hamming checks of order 4.
+    rows = [list(range(15))]
+    for check in range(4):
+        mask = 1 << (3 - check)
+        rows.append([bit for bit in range(15) if (bit + 1) & mask])
+    code = triorthogonal_code(BinaryMatrix(15, rows))
+    other = quantum_reed_muller_code(4)
+    assert code.x_stabs_binary().rank() == other.x_stabs_binary().rank()
+    assert code.num_z_stabs() == other.num_z_stabs()
+    assert code.num_x_logicals() == 1
+
+
+@pytest.mark.parametrize(
+    "degree, num_variables, num_logicals",
+    [(1, 2, 2), (1, 3, 0), (2, 3, 6), (2, 4, 6), (3, 5, 20)],
+)
+def test_reed_muller_css_code(degree, num_variables, num_logicals):
+    code = reed_muller_css_code(degree, num_variables)
+    assert len(code) == 2 ** num_variables
+    assert code.x_stabs_binary() == code.z_stabs_binary()
+    assert code.num_x_logicals() == num_logicals
+
+
+def test_reed_muller_css_code_distance():
+    code = reed_muller_css_code(2, 4)
+    weight_two = PauliOperator(16, [0, 1], [X, X])
+    weight_four = PauliOperator(16, [0, 1, 2, 3], [X, X, X, X])
+    assert code.classify(weight_two) == "not_in_normalizer"
+    assert code.classify(weight_four) == "logical"
+
+
+def test_reed_muller_css_code_invalid_degree():
+    with pytest.raises(ValueError):
+        reed_muller_css_code(1, 4)
+    with pytest.raises(ValueError):
+        reed_muller_css_code(4, 4)
+    with pytest.raises(ValueError):
+        reed_muller_css_code(10, 11)
+
+
+@pytest.mark.parametrize("num_variables", [4, 5])
+def test_reed_muller_triorthogonal_code_of_degree_one(num_variables):
+    code = reed_muller_triorthogonal_code(1, num_variables)
+    other = quantum_reed_muller_code(num_variables)
+    assert code.x_stabs_binary().rank() == other.x_stabs_binary().rank()
+    assert code.num_z_stabs() == other.num_z_stabs()
+    assert code.num_x_logicals() == 1
+
+
+def test_reed_muller_triorthogonal_code_of_degree_two():
+    code = reed_muller_triorthogonal_code(2, 7)
+    assert len(code) == 127
+    assert code.num_x_stabs() == 28
+    assert code.num_x_logicals() == 1
+
+
+def test_reed_muller_triorthogonal_code_invalid_degree():
+    with pytest.raises(ValueError):
+        reed_muller_triorthogonal_code(0, 4)
+    with pytest.raises(ValueError):
+        reed_muller_triorthogonal_code(1, 3)
+    with pytest.raises(ValueError):
+        reed_muller_triorthogonal_code(1, 11)
+
+
+def test_triorthogonal_code_with_odd_overlap():
+    with pytest.raises(ValueError):
+        triorthogonal_code(BinaryMatrix(4, [[0, 1, 2, 3], [0, 1, 2]]))
+
+
+@pytest.mark.parametrize("distance", [2, 3, 5])
+def test_planar_surface_code(distance):
+    code = planar_surface_code(distance)
+    length = distance ** 2 + (distance - 1) ** 2
+    assert len(code) == length
+    assert code.num_x_stabs() == distance * (distance - 1)
+    assert code.num_z_stabs() == distance * (distance - 1)
+    assert code.num_x_logicals() == 1
+
+    first_column = [(2 * distance - 1) * row for row in range(distance)]
+    first_row = list(range(distance))
+    assert code.classify(PauliOperator(length, first_column, [X] * distance)) == "logical"
+    assert code.classify(PauliOperator(length, first_row, [Z] * distance)) == "logical"
+
+
+def test_planar_surface_code_with_invalid_distance():
+    with pytest.raises(ValueError):
+        planar_surface_code(1)
@@ -0,0 +1,65 @@
+import pytest
+from qecstruct import (
+    BinaryVector,
+    Pauli,
+    PauliOperator,
+    Rng,
+    SmallSetFlipDecoder,
+    hamming_code,
+    hypergraph_product,
+    quantum_reed_muller_code,
+    random_regular_code,
+    repetition_code,
+)
+
+X, Y, Z = Pauli.X(), Pauli.Y(), Pauli.Z()
+
+
+@pytest.mark.parametrize(
+    "code",
+    [
+        hypergraph_product(hamming_code(), hamming_code()),
+        hypergraph_product(repetition_code(5), repetition_code(5)),
+    ],
+)
+def test_single_qubit_errors_are_corrected(code):
+    decoder = SmallSetFlipDecoder(code)
+    for position in range(len(code)):
+        for pauli in (X, Y, Z):
+            error = PauliOperator(len(code), [position], [pauli])
+            correction, stuck = decoder.decode(code.syndrome_of(error))
+            assert not stuck
+            assert code.classify(error.apply(correction)) in ("trivial", "stabilizer")
+
+
+def test_corrections_have_the_syndrome_unless_stuck():
+    classical_code = random_regular_code(12, 9, 3, 4, Rng(1))
+    code = hypergraph_product(classical_code, classical_code)
+    decoder = SmallSetFlipDecoder(code)
+    for first in range(0, len(code), 7):
+        for second in range(first + 1, len(code), 11):
+            error = PauliOperator(len(code), [first, second], [X, Z])
+            syndrome = code.syndrome_of(error)
+            correction, stuck = decoder.decode(syndrome)
+            if not stuck:
+                assert code.syndrome_of(correction) == syndrome
+
+
+def test_stuck_on_the_boundary_of_the_surface_code():
+    code = hypergraph_product(repetition_code(5), repetition_code(5))
+    decoder = SmallSetFlipDecoder(code)
+    error = PauliOperator(len(code), [0, 5], [X, X])
+    correction, stuck = decoder.decode(code.syndrome_of(error))
+
+    assert stuck
+    assert correction.weight() == 0
+
+
+def test_invalid_inputs():
+    with pytest.raises(ValueError):
+        SmallSetFlipDecoder(quantum_reed_muller_code(6))
+
+    code = hypergraph_product(repetition_code(3), repetition_code(3))
+    decoder = SmallSetFlipDecoder(code)
+    with pytest.raises(ValueError):
+        decoder.decode((BinaryVector(2, []), BinaryVector(6, [])))
@@ -0,0 +1,83 @@
+import pickle
+import pytest
+from qecstruct import (
+    BinaryVector,
+    Pauli,
+    PauliOperator,
+    SubsystemCode,
+    subsystem_bacon_shor_code,
+)
+
+X, Y, Z = Pauli.X(), Pauli.Y(), Pauli.Z()
+
+
+def test_non_commuting_gauge_generators():
+    code = SubsystemCode(
+        [PauliOperator(3, [0, 1], [X, X]), PauliOperator(3, [1, 2], [Z, Z])]
+    )
+    assert len(code) == 3
+    assert code.num_stabs() == 0
+    assert code.num_gauge_qubits() == 1
+    assert code.num_logicals() == 2
+    assert len(code.bare_logicals()) == 4
+    assert len(code.dressed_logicals()) == 4
+
+
+def test_commuting_gauge_generators_form_a_stabilizer_code():
+    code = SubsystemCode(
+        [PauliOperator(3, [0, 1], [Z, Z]), PauliOperator(3, [1, 2], [Z, Z])]
+    )
+    assert code.num_stabs() == 2
+    assert code.num_gauge_qubits() == 0
+    assert code.num_logicals() == 1
+    assert code.has_bare_logical(PauliOperator(3, [0, 1, 2], [X, X, X]))
+    assert code.has_dressed_logical(PauliOperator(3, [0, 1, 2], [X, X, X]))
+
+
+def test_gauge_generators_with_different_lengths():
+    with pytest.raises(ValueError):
+        SubsystemCode([PauliOperator(2, [0], [X]), PauliOperator(3, [0], [X])])
+
+
+@pytest.mark.parametrize("rows, columns", [(2, 2), (3, 3), (3, 5)])
+def test_bacon_shor_code(rows, columns):
+    code = subsystem_bacon_shor_code(rows, columns)
+    assert len(code) == rows * columns
+    assert code.num_stabs() == rows + columns - 2
+    assert code.num_gauge_qubits() == (rows - 1) * (columns - 1)
+    assert code.num_logicals() == 1
+    for stabilizer in code.stabilizers():
+        assert code.gauge_syndrome_of(stabilizer).is_zero()
+    for logical in code.bare_logicals():
+        assert code.has_bare_logical(logical)
+        assert code.gauge_syndrome_of(logical).is_zero()
+    for logical in code.dressed_logicals():
+        assert code.has_dressed_logical(logical)
+
+
+def test_bacon_shor_logicals():
+    code = subsystem_bacon_shor_code(3, 3)
+    # A row of Xs is a bare logical and it becomes
+    # a dressed logical when multiplied by a gauge operator.
+    row_of_xs = PauliOperator(9, [0, 1, 2], [X, X, X])
+    assert code.has_bare_logical(row_of_xs)
+    dressed = PauliOperator(9, [0, 1, 2], [Y, Y, X])
+    assert code.has_dressed_logical(dressed)
+    assert not code.has_bare_logical(dressed)
+    assert code.has_gauge(PauliOperator(9, [0, 1], [Z, Z]))
+    assert not code.has_stabilizer(PauliOperator(9, [0, 1], [Z, Z]))
+
+
+def test_bacon_shor_syndromes():
+    code = subsystem_bacon_shor_code(3, 3)
+    error = PauliOperator(9, [4], [Z])
+    # The error anticommutes with the X gauge operators above and below.
+    assert code.gauge_syndrome_of(error) == BinaryVector(12, [1, 4])
+    assert code.syndrome_of(error).weight() == 2
+
+
+def test_pickle():
+    code = subsystem_bacon_shor_code(2, 3)
+    other = pickle.loads(pickle.dumps(code))
+    assert other.stabs_binary() == code.stabs_binary()
+    assert other.num_logicals() == 1
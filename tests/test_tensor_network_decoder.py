@@ -0,0 +1,103 @@
+import pickle
+import pytest
+from qecstruct import (
+    BiasedNoise,
+    BinarySymmetricChannel,
+    BinaryVector,
+    DepolarizingNoise,
+    MaximumLikelihoodDecoder,
+    Pauli,
+    PauliOperator,
+    Rng,
+    TensorNetworkDecoder,
+    planar_surface_code,
+    steane_code,
+)
+
+X, Y, Z = Pauli.X(), Pauli.Y(), Pauli.Z()
+
+
+@pytest.mark.parametrize("noise", [DepolarizingNoise(0.15), BinarySymmetricChannel(0.1)])
+def test_large_bond_dimension_matches_exact_decoder(noise):
+    code = planar_surface_code(3)
+    exact_decoder = MaximumLikelihoodDecoder(code, noise)
+    decoder = TensorNetworkDecoder(code, noise, bond_dimension=64)
+    rng = Rng(3)
+    for _ in range(5):
+        x_part = BinarySymmetricChannel(0.2).sample(13, rng)
+        error = PauliOperator(13, list(x_part), [X] * len(list(x_part)))
+        syndrome = code.syndrome_of(error)
+        expected = exact_decoder.coset_probabilities(syndrome)
+        for value, expected_value in zip(decoder.coset_probabilities(syndrome), expected):
+            assert value == pytest.approx(expected_value)
+
+
+@pytest.mark.parametrize("bond_dimension", [2, 8])
+def test_single_qubit_errors_are_corrected(bond_dimension):
+    code = planar_surface_code(5)
+    decoder = TensorNetworkDecoder(code, DepolarizingNoise(0.05), bond_dimension)
+    for position in range(len(code)):
+        for pauli in (X, Y, Z):
+            error = PauliOperator(len(code), [position], [pauli])
+            correction, probability = decoder.decode(code.syndrome_of(error))
+            assert code.classify(error.apply(correction)) in ("trivial", "stabilizer")
+            assert 0.25 <= probability <= 1
+
+
+def test_dephasing_noise_gives_z_corrections():
+    code = planar_surface_code(5)
+    decoder = TensorNetworkDecoder(code, BiasedNoise(0.1, float("inf")), bond_dimension=4)
+    error = PauliOperator(41, [6, 18], [Z, Z])
+    syndrome = code.syndrome_of(error)
+    correction, probability = decoder.decode(syndrome)
+
+    assert correction.x_part().weight() == 0
+    assert code.classify(error.apply(correction)) in ("trivial", "stabilizer")
+    # The cosets with an X logical have no Z only errors.
+    probabilities = decoder.coset_probabilities(syndrome)
+    assert probabilities[1] == 0 and probabilities[3] == 0
+    assert sum(probabilities) == pytest.approx(1)
+
+
+def test_biased_noise():
+    noise = BiasedNoise(0.3, float("inf"))
+    error = noise.sample(1000, Rng(1))
+    assert error.x_part().weight() == 0
+    assert error.weight() > 0
+    assert noise.error_probability() == 0.3
+    assert noise.bias() == float("inf")
+
+    noise = pickle.loads(pickle.dumps(BiasedNoise(0.2, 10)))
+    assert (noise.error_probability(), noise.bias()) == (0.2, 10)
+
+    with pytest.raises(ValueError):
+        BiasedNoise(1.5, 1)
+    with pytest.raises(ValueError):
+        BiasedNoise(0.1, -1)
+
+
+def test_equivalent_code_with_other_stabilizer_generators():
+    code = planar_surface_code(3).canonical_form()
+    decoder = TensorNetworkDecoder(code, DepolarizingNoise(0.05))
+
+    assert decoder.distance() == 3
+    for position in range(len(code)):
+        error = PauliOperator(len(code), [position], [Y])
+        correction, _ = decoder.decode(code.syndrome_of(error))
+        assert code.classify(error.apply(correction)) in ("trivial", "stabilizer")
+
+
+def test_invalid_inputs():
+    code = planar_surface_code(3)
+    with pytest.raises(TypeError):
+        TensorNetworkDecoder(code, 0.1)
+    with pytest.raises(TypeError):
+        TensorNetworkDecoder(3, DepolarizingNoise(0.1))
+    with pytest.raises(ValueError):
+        TensorNetworkDecoder(steane_code(), DepolarizingNoise(0.1))
+    with pytest.raises(ValueError):
+        TensorNetworkDecoder(code, DepolarizingNoise(0.1), bond_dimension=0)
+
+    decoder = TensorNetworkDecoder(code, DepolarizingNoise(0.1))
+    with pytest.raises(ValueError):
+        decoder.decode((BinaryVector(5, []), BinaryVector(6, [])))